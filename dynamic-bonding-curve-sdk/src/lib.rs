@@ -1 +1,2 @@
 pub mod quote;
+pub mod tests;
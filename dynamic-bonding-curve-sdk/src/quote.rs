@@ -1,26 +1,77 @@
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::Mint,
+};
 use anyhow::{ensure, Context, Result};
 use dynamic_bonding_curve::{
     activation_handler::ActivationType,
     params::swap::TradeDirection,
-    state::{fee::FeeMode, PoolConfig, SwapResult, VirtualPool},
+    state::{
+        apply_curve_variant_to_config, fee::FeeMode, CurveVariants, PoolConfig, SwapResult,
+        VirtualPool,
+    },
+    token::calculate_transfer_fee_excluded_amount,
 };
 
+#[derive(Debug)]
+pub struct QuoteResult {
+    pub swap_result: SwapResult,
+    /// output amount user receives after transfer fee of output token
+    pub transfer_fee_excluded_amount_out: u64,
+}
+
+/// transfer fee of mint account data in the epoch, none for spl token mints and token 2022 mints without transfer fee
+pub fn get_epoch_transfer_fee(mint_data: &[u8], epoch: u64) -> Result<Option<TransferFee>> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    Ok(mint
+        .get_extension::<TransferFeeConfig>()
+        .ok()
+        .map(|transfer_fee_config| *transfer_fee_config.get_epoch_fee(epoch)))
+}
+
+/// copy of config with curve variant of the pool applied, curve variants of config is required if the pool uses a curve variant
+pub fn get_pool_config(
+    config: &PoolConfig,
+    virtual_pool: &VirtualPool,
+    curve_variants: Option<&CurveVariants>,
+) -> Result<PoolConfig> {
+    let mut pool_config = *config;
+    apply_curve_variant_to_config(
+        &virtual_pool.config,
+        &mut pool_config,
+        virtual_pool.curve_variant,
+        curve_variants,
+    )?;
+    Ok(pool_config)
+}
+
+/// config must have curve variant of the pool applied, see `get_pool_config`,
+/// transfer fees are the current epoch transfer fees of input and output mint, see `get_epoch_transfer_fee`
 pub fn quote_exact_in(
     virtual_pool: &VirtualPool,
     config: &PoolConfig,
     swap_base_for_quote: bool,
     current_timestamp: u64,
     current_slot: u64,
-    transfer_fee_excluded_amount_in: u64, // must be calculated from outside
+    amount_in: u64,
     has_referral: bool,
-) -> Result<SwapResult> {
+    input_transfer_fee: Option<&TransferFee>,
+    output_transfer_fee: Option<&TransferFee>,
+) -> Result<QuoteResult> {
     let mut virtual_pool = *virtual_pool;
 
     ensure!(
-        !virtual_pool.is_curve_complete(config.migration_quote_threshold),
+        !virtual_pool.is_curve_complete(virtual_pool.get_migration_quote_threshold(config)),
         "virtual pool is completed"
     );
 
+    ensure!(!virtual_pool.is_paused(), "virtual pool is paused");
+
+    let transfer_fee_excluded_amount_in =
+        calculate_transfer_fee_excluded_amount(input_transfer_fee, amount_in)?.amount;
     ensure!(transfer_fee_excluded_amount_in > 0, "amount is zero");
 
     virtual_pool.update_pre_swap(config, current_timestamp)?;
@@ -31,6 +82,16 @@ pub fn quote_exact_in(
         ActivationType::Timestamp => current_timestamp,
     };
 
+    ensure!(
+        !virtual_pool.can_complete_curve_by_deadline(config, current_point)?,
+        "virtual pool is completed"
+    );
+
+    ensure!(
+        swap_base_for_quote || !virtual_pool.is_migration_deadline_missed(config, current_point)?,
+        "migration deadline is missed, virtual pool only allows selling base token"
+    );
+
     let trade_direction = if swap_base_for_quote {
         TradeDirection::BaseToQuote
     } else {
@@ -45,5 +106,17 @@ pub fn quote_exact_in(
         current_point,
     )?;
 
-    Ok(swap_result)
+    ensure!(
+        !virtual_pool.is_circuit_breaker_triggered(config, swap_result.next_sqrt_price)?,
+        "circuit breaker is triggered"
+    );
+
+    let transfer_fee_excluded_amount_out =
+        calculate_transfer_fee_excluded_amount(output_transfer_fee, swap_result.output_amount)?
+            .amount;
+
+    Ok(QuoteResult {
+        swap_result,
+        transfer_fee_excluded_amount_out,
+    })
 }
@@ -0,0 +1,166 @@
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions,
+        StateWithExtensionsMut,
+    },
+    state::Mint,
+};
+use dynamic_bonding_curve::{
+    constants::MAX_SQRT_PRICE,
+    params::swap::TradeDirection,
+    state::{
+        fee::{FeeMode, VolatilityTracker},
+        CollectFeeMode, LiquidityDistributionConfig, PoolConfig, VirtualPool,
+    },
+    token::calculate_transfer_fee_excluded_amount,
+};
+
+use crate::quote::{get_epoch_transfer_fee, quote_exact_in};
+
+const TRANSFER_FEE_BPS: u16 = 100; // 1%
+const MAX_TRANSFER_FEE: u64 = u64::MAX;
+
+fn get_config() -> PoolConfig {
+    let mut config = PoolConfig {
+        migration_quote_threshold: 50_000_000_000,
+        sqrt_start_price: 1u128 << 64, // price = 1
+        collect_fee_mode: CollectFeeMode::QuoteToken.into(),
+        ..Default::default()
+    };
+    config.curve[0] = LiquidityDistributionConfig {
+        sqrt_price: MAX_SQRT_PRICE,
+        liquidity: 1_000_000_000_000_000_000_000_000u128 << 64,
+    };
+    for point in config.curve.iter_mut().skip(1) {
+        point.sqrt_price = MAX_SQRT_PRICE;
+    }
+    config
+}
+
+fn get_pool(config: &PoolConfig) -> VirtualPool {
+    let mut pool = VirtualPool::default();
+    pool.initialize(
+        VolatilityTracker::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        config.sqrt_start_price,
+        0,
+        0,
+        config.get_initial_base_supply().unwrap(),
+    );
+    pool
+}
+
+/// token 2022 mint data with transfer fee extension
+fn get_transfer_fee_mint_data() -> Vec<u8> {
+    let account_len =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+            .unwrap();
+    let mut data = vec![0; account_len];
+    let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    let transfer_fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: MAX_TRANSFER_FEE.into(),
+        transfer_fee_basis_points: TRANSFER_FEE_BPS.into(),
+    };
+    let transfer_fee_config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+    transfer_fee_config.older_transfer_fee = transfer_fee;
+    transfer_fee_config.newer_transfer_fee = transfer_fee;
+    mint.base = Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    };
+    mint.pack_base();
+    mint.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn test_get_epoch_transfer_fee() {
+    let mint_data = get_transfer_fee_mint_data();
+    let transfer_fee = get_epoch_transfer_fee(&mint_data, 0).unwrap().unwrap();
+    assert_eq!(
+        u16::from(transfer_fee.transfer_fee_basis_points),
+        TRANSFER_FEE_BPS
+    );
+    assert_eq!(u64::from(transfer_fee.maximum_fee), MAX_TRANSFER_FEE);
+}
+
+#[test]
+fn test_quote_exact_in_with_transfer_fee() {
+    let config = get_config();
+    let pool = get_pool(&config);
+    let mint_data = get_transfer_fee_mint_data();
+    let transfer_fee = get_epoch_transfer_fee(&mint_data, 0).unwrap().unwrap();
+    let amount_in = 1_000_000_000;
+
+    for swap_base_for_quote in [false, true] {
+        let (input_transfer_fee, output_transfer_fee, trade_direction) = if swap_base_for_quote {
+            (Some(&transfer_fee), None, TradeDirection::BaseToQuote)
+        } else {
+            (None, Some(&transfer_fee), TradeDirection::QuoteToBase)
+        };
+        let result = quote_exact_in(
+            &pool,
+            &config,
+            swap_base_for_quote,
+            0,
+            0,
+            amount_in,
+            false,
+            input_transfer_fee,
+            output_transfer_fee,
+        )
+        .unwrap();
+
+        // program math: swap with the amount vault receives, user receives output amount minus transfer fee
+        let transfer_fee_excluded_amount_in =
+            calculate_transfer_fee_excluded_amount(input_transfer_fee, amount_in)
+                .unwrap()
+                .amount;
+        let fee_mode =
+            FeeMode::get_fee_mode(config.collect_fee_mode, trade_direction, false).unwrap();
+        let swap_result = get_pool(&config)
+            .get_swap_result(
+                &config,
+                transfer_fee_excluded_amount_in,
+                &fee_mode,
+                trade_direction,
+                0,
+            )
+            .unwrap();
+        let transfer_fee_excluded_amount_out =
+            calculate_transfer_fee_excluded_amount(output_transfer_fee, swap_result.output_amount)
+                .unwrap()
+                .amount;
+
+        assert_eq!(result.swap_result.output_amount, swap_result.output_amount);
+        assert_eq!(
+            result.transfer_fee_excluded_amount_out,
+            transfer_fee_excluded_amount_out
+        );
+
+        // transfer fee of output is what token 2022 charges on the output transfer
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data).unwrap();
+        let output_transfer_fee_amount = if swap_base_for_quote {
+            0
+        } else {
+            mint.get_extension::<TransferFeeConfig>()
+                .unwrap()
+                .calculate_epoch_fee(0, swap_result.output_amount)
+                .unwrap()
+        };
+        assert_eq!(
+            result.transfer_fee_excluded_amount_out,
+            swap_result.output_amount - output_transfer_fee_amount
+        );
+        if !swap_base_for_quote {
+            assert!(result.transfer_fee_excluded_amount_out < result.swap_result.output_amount);
+        }
+    }
+}
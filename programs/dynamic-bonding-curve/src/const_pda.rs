@@ -13,6 +13,30 @@ pub mod pool_authority {
     pub const BUMP: u8 = POOL_AUTHORITY_AND_BUMP.1;
 }
 
+pub mod global_state {
+    use super::*;
+
+    const GLOBAL_STATE_AND_BUMP: ([u8; 32], u8) = ed25519::derive_program_address(
+        &[crate::constants::seeds::GLOBAL_STATE_PREFIX],
+        &crate::ID_CONST.to_bytes(),
+    );
+
+    pub const ID: Pubkey = Pubkey::new_from_array(GLOBAL_STATE_AND_BUMP.0);
+    pub const BUMP: u8 = GLOBAL_STATE_AND_BUMP.1;
+}
+
+pub mod protocol_config {
+    use super::*;
+
+    const PROTOCOL_CONFIG_AND_BUMP: ([u8; 32], u8) = ed25519::derive_program_address(
+        &[crate::constants::seeds::PROTOCOL_CONFIG_PREFIX],
+        &crate::ID_CONST.to_bytes(),
+    );
+
+    pub const ID: Pubkey = Pubkey::new_from_array(PROTOCOL_CONFIG_AND_BUMP.0);
+    pub const BUMP: u8 = PROTOCOL_CONFIG_AND_BUMP.1;
+}
+
 // Potential optimization on event authority too since anchor internally do Pubkey::find_program_address during runtime.
 
 #[cfg(test)]
@@ -29,4 +53,24 @@ mod tests {
         assert_eq!(pool_authority::ID, derived_pool_authority);
         assert_eq!(pool_authority::BUMP, derived_bump);
     }
+
+    #[test]
+    fn test_const_global_state() {
+        let (derived_global_state, derived_bump) = Pubkey::find_program_address(
+            &[crate::constants::seeds::GLOBAL_STATE_PREFIX],
+            &crate::ID,
+        );
+        assert_eq!(global_state::ID, derived_global_state);
+        assert_eq!(global_state::BUMP, derived_bump);
+    }
+
+    #[test]
+    fn test_const_protocol_config() {
+        let (derived_protocol_config, derived_bump) = Pubkey::find_program_address(
+            &[crate::constants::seeds::PROTOCOL_CONFIG_PREFIX],
+            &crate::ID,
+        );
+        assert_eq!(protocol_config::ID, derived_protocol_config);
+        assert_eq!(protocol_config::BUMP, derived_bump);
+    }
 }
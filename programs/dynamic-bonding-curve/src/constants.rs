@@ -1,5 +1,3 @@
-use static_assertions::const_assert;
-
 pub const MIN_SQRT_PRICE: u128 = 4295048016;
 pub const MAX_SQRT_PRICE: u128 = 79226673521066979257578248091;
 
@@ -8,6 +6,16 @@ pub const BASIS_POINT_MAX: u64 = 10_000;
 pub const U24_MAX: u32 = 0xffffff;
 
 pub const ONE_Q64: u128 = 1u128 << 64;
+
+pub const MAX_PROTOCOL_ADMIN: usize = 8;
+
+pub const MAX_FEE_SPLIT_RECIPIENT: usize = 8;
+
+pub const MAX_SURPLUS_SHARE_TIER: usize = 8;
+
+pub const MAX_POOL_CREATION_FEE_PROTOCOL_PERCENT: u8 = 50; // 50 %
+
+pub const MAX_BASE_TRANSFER_FEE_BPS: u16 = 1000; // 10%
 pub mod dynamic_fee {
 
     pub const BIN_STEP_BPS_DEFAULT: u16 = 1;
@@ -36,8 +44,10 @@ pub const MIN_MIGRATED_POOL_FEE_BPS: u16 = 10; // 0.1%
 pub const MAX_MIGRATED_POOL_FEE_BPS: u16 = 1000; // 10%
 
 pub const MAX_CURVE_POINT: usize = 16;
-pub const MAX_CURVE_POINT_CONFIG: usize = 20;
-const_assert!(MAX_CURVE_POINT <= MAX_CURVE_POINT_CONFIG);
+
+pub const MAX_CURVE_VARIANT: usize = 4;
+
+pub const MAX_TOKEN_METADATA_CREATOR: usize = 5;
 
 pub const SWAP_BUFFER_PERCENTAGE: u8 = 25; // 25%
 
@@ -101,4 +111,13 @@ pub mod seeds {
     pub const PARTNER_METADATA_PREFIX: &[u8] = b"partner_metadata";
     pub const VIRTUAL_POOL_METADATA_PREFIX: &[u8] = b"virtual_pool_metadata";
     pub const BASE_LOCKER_PREFIX: &[u8] = b"base_locker";
+    pub const GLOBAL_STATE_PREFIX: &[u8] = b"global_state";
+    pub const PROTOCOL_CONFIG_PREFIX: &[u8] = b"protocol_config";
+    pub const DELEGATED_CLAIM_FEE_OPERATOR_PREFIX: &[u8] = b"delegated_cf_operator";
+    pub const FEE_SPLIT_PREFIX: &[u8] = b"fee_split";
+    pub const FEE_SPLIT_VAULT_PREFIX: &[u8] = b"fee_split_vault";
+    pub const CREATOR_FEE_SPLIT_PREFIX: &[u8] = b"creator_fee_split";
+    pub const CREATOR_FEE_SPLIT_VAULT_PREFIX: &[u8] = b"creator_fee_split_vault";
+    pub const CURVE_VARIANTS_PREFIX: &[u8] = b"curve_variants";
+    pub const TOKEN_METADATA_CONFIG_PREFIX: &[u8] = b"token_metadata_config";
 }
@@ -139,4 +139,70 @@ pub enum PoolError {
 
     #[msg("Invalid migrated pool fee params")]
     InvalidMigratedPoolFee,
+
+    #[msg("Migration deadline is not reached")]
+    MigrationDeadlineIsNotReached,
+
+    #[msg("Invalid circuit breaker params")]
+    InvalidCircuitBreaker,
+
+    #[msg("Pool is paused")]
+    PoolIsPaused,
+
+    #[msg("Pool is not paused")]
+    PoolIsNotPaused,
+
+    #[msg("Circuit breaker is triggered")]
+    CircuitBreakerTriggered,
+
+    #[msg("Program is under emergency stop")]
+    EmergencyStopped,
+
+    #[msg("Exceeded max admin")]
+    ExceededMaxAdmin,
+
+    #[msg("Cannot remove the last admin")]
+    CannotRemoveLastAdmin,
+
+    #[msg("Invalid new fee claimer")]
+    InvalidNewFeeClaimer,
+
+    #[msg("Invalid operator scope")]
+    InvalidOperatorScope,
+
+    #[msg("Invalid fee split recipients")]
+    InvalidFeeSplitRecipients,
+
+    #[msg("Invalid surplus share")]
+    InvalidSurplusShare,
+
+    #[msg("Invalid pool creation fee")]
+    InvalidPoolCreationFee,
+
+    #[msg("Pool creation is not approved by pool creator authority")]
+    PoolCreationNotApproved,
+
+    #[msg("Invalid curve variant")]
+    InvalidCurveVariant,
+
+    #[msg("Invalid base mint")]
+    InvalidBaseMint,
+
+    #[msg("Invalid base transfer fee")]
+    InvalidBaseTransferFee,
+
+    #[msg("Invalid non-transferable option")]
+    InvalidNonTransferableOption,
+
+    #[msg("Invalid token metadata config")]
+    InvalidTokenMetadataConfig,
+
+    #[msg("Token metadata is immutable")]
+    ImmutableTokenMetadata,
+
+    #[msg("Invalid token metadata update")]
+    InvalidTokenMetadataUpdate,
+
+    #[msg("Migration deadline is missed, pool only allows selling base token")]
+    MigrationDeadlineMissed,
 }
@@ -2,11 +2,13 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    constants::MAX_SURPLUS_SHARE_TIER,
     params::{
         fee_parameters::PoolFeeParameters, liquidity_distribution::LiquidityDistributionParameters,
     },
     state::SwapResult,
-    ConfigParameters, LockedVestingParams, SwapParameters,
+    ConfigParameters, CurveVariantParameters, FeeSplitRecipientParams, LockedVestingParams,
+    SwapParameters, TokenMetadataConfigParameters, UpdateTokenMetadataParameters,
 };
 
 /// Create partner metadata
@@ -73,6 +75,84 @@ pub struct EvtCloseClaimFeeOperator {
     pub operator: Pubkey,
 }
 
+/// Create delegated claim fee operator
+#[event]
+pub struct EvtCreateDelegatedClaimFeeOperator {
+    pub delegated_operator: Pubkey,
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub scope: u8,
+}
+
+/// Close delegated claim fee operator
+#[event]
+pub struct EvtCloseDelegatedClaimFeeOperator {
+    pub delegated_operator: Pubkey,
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub scope: u8,
+}
+
+/// Create global state
+#[event]
+pub struct EvtCreateGlobalState {
+    pub global_state: Pubkey,
+}
+
+/// Set emergency stop
+#[event]
+pub struct EvtSetEmergencyStop {
+    pub admin: Pubkey,
+    pub is_emergency_stopped: bool,
+}
+
+/// Initialize protocol config
+#[event]
+pub struct EvtInitializeProtocolConfig {
+    pub admin: Pubkey,
+}
+
+/// Propose admin
+#[event]
+pub struct EvtProposeAdmin {
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub replaced_admin: Pubkey,
+}
+
+/// Accept admin
+#[event]
+pub struct EvtAcceptAdmin {
+    pub new_admin: Pubkey,
+    pub replaced_admin: Pubkey,
+}
+
+/// Propose remove admin
+#[event]
+pub struct EvtProposeRemoveAdmin {
+    pub admin: Pubkey,
+    pub removed_admin: Pubkey,
+}
+
+/// Remove admin
+#[event]
+pub struct EvtRemoveAdmin {
+    pub admin: Pubkey,
+    pub removed_admin: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdateSurplusShareTiers {
+    pub admin: Pubkey,
+    pub surplus_share_tiers: [u8; MAX_SURPLUS_SHARE_TIER],
+}
+
+#[event]
+pub struct EvtUpdatePoolCreationFeeProtocolPercent {
+    pub admin: Pubkey,
+    pub percent: u8,
+}
+
 #[event]
 pub struct EvtInitializePool {
     pub pool: Pubkey,
@@ -81,6 +161,9 @@ pub struct EvtInitializePool {
     pub base_mint: Pubkey,
     pub pool_type: u8,
     pub activation_point: u64,
+    pub pool_creation_fee: u64,
+    pub pool_creation_fee_token: u8,
+    pub curve_variant: u8,
 }
 
 #[event]
@@ -110,6 +193,46 @@ pub struct EvtClaimProtocolFee {
     pub token_quote_amount: u64,
 }
 
+#[event]
+pub struct EvtProposeFeeClaimer {
+    pub config: Pubkey,
+    pub fee_claimer: Pubkey,
+    pub new_fee_claimer: Pubkey,
+}
+
+#[event]
+pub struct EvtCancelFeeClaimer {
+    pub config: Pubkey,
+    pub fee_claimer: Pubkey,
+    pub new_fee_claimer: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdateFeeClaimer {
+    pub config: Pubkey,
+    pub fee_claimer: Pubkey,
+    pub new_fee_claimer: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdateLeftoverReceiver {
+    pub config: Pubkey,
+    pub leftover_receiver: Pubkey,
+    pub new_leftover_receiver: Pubkey,
+}
+
+#[event]
+pub struct EvtPausePool {
+    pub pool: Pubkey,
+    pub config: Pubkey,
+}
+
+#[event]
+pub struct EvtResumePool {
+    pub pool: Pubkey,
+    pub config: Pubkey,
+}
+
 #[event]
 pub struct EvtClaimTradingFee {
     pub pool: Pubkey,
@@ -117,6 +240,13 @@ pub struct EvtClaimTradingFee {
     pub token_quote_amount: u64,
 }
 
+#[event]
+pub struct EvtBatchClaimTradingFee {
+    pub config: Pubkey,
+    pub number_of_pools: u64,
+    pub token_quote_amount: u64,
+}
+
 #[event]
 pub struct EvtClaimCreatorTradingFee {
     pub pool: Pubkey,
@@ -159,6 +289,20 @@ pub struct EvtWithdrawLeftover {
     pub leftover_amount: u64,
 }
 
+#[event]
+pub struct EvtProposePoolCreator {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct EvtCancelPoolCreator {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub pending_creator: Pubkey,
+}
+
 #[event]
 pub struct EvtUpdatePoolCreator {
     pub pool: Pubkey,
@@ -178,3 +322,91 @@ pub struct EvtPartnerWithdrawMigrationFee {
     pub pool: Pubkey,
     pub fee: u64,
 }
+
+#[event]
+pub struct EvtCreateFeeSplit {
+    pub config: Pubkey,
+    pub fee_split: Pubkey,
+    pub recipients: Vec<FeeSplitRecipientParams>,
+}
+
+#[event]
+pub struct EvtClaimFeeSplit {
+    pub fee_split: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub sol_amount: u64,
+}
+
+#[event]
+pub struct EvtCreateCreatorFeeSplit {
+    pub pool: Pubkey,
+    pub creator_fee_split: Pubkey,
+    pub co_creators: Vec<FeeSplitRecipientParams>,
+}
+
+#[event]
+pub struct EvtClaimCreatorFeeSplit {
+    pub creator_fee_split: Pubkey,
+    pub co_creator: Pubkey,
+    pub token_base_amount: u64,
+    pub token_quote_amount: u64,
+}
+
+#[event]
+pub struct EvtDistributeSurplus {
+    pub pool: Pubkey,
+    pub partner_surplus_amount: u64,
+    pub creator_surplus_amount: u64,
+    pub protocol_surplus_amount: u64,
+}
+
+#[event]
+pub struct EvtUpdatePoolCreatorAuthority {
+    pub config: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub new_pool_creator_authority: Pubkey,
+}
+
+#[event]
+pub struct EvtCreateCurveVariants {
+    pub config: Pubkey,
+    pub curve_variants: Pubkey,
+    pub variants: Vec<CurveVariantParameters>,
+}
+
+#[event]
+pub struct EvtCreateTokenMetadataConfig {
+    pub config: Pubkey,
+    pub token_metadata_config: Pubkey,
+    pub params: TokenMetadataConfigParameters,
+}
+
+#[event]
+pub struct EvtUpdateConfig {
+    pub config: Pubkey,
+    pub version: u8,
+    pub pool_count: u64,
+    pub config_parameters: ConfigParameters,
+}
+
+#[event]
+pub struct EvtCloseConfig {
+    pub config: Pubkey,
+    pub rent_receiver: Pubkey,
+}
+
+#[event]
+pub struct EvtCloseVirtualPool {
+    pub pool: Pubkey,
+    pub config: Pubkey,
+    pub rent_receiver: Pubkey,
+}
+
+#[event]
+pub struct EvtUpdateTokenMetadata {
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    pub update_authority: Pubkey,
+    pub params: UpdateTokenMetadataParameters,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{const_pda, state::ProtocolConfig, EvtAcceptAdmin, PoolError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdminCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.pending_admin == pending_admin.key() @ PoolError::InvalidAdmin,
+    )]
+    pub pending_admin: Signer<'info>,
+}
+
+pub fn handle_accept_admin(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    let replaced_admin = protocol_config.pending_replaced_admin;
+    protocol_config.accept_admin()?;
+
+    emit_cpi!(EvtAcceptAdmin {
+        new_admin: ctx.accounts.pending_admin.key(),
+        replaced_admin,
+    });
+
+    Ok(())
+}
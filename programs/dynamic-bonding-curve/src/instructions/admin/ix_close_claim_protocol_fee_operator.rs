@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{assert_eq_admin, state::ClaimFeeOperator, EvtCloseClaimFeeOperator, PoolError};
+use crate::{
+    const_pda,
+    state::{ClaimFeeOperator, ProtocolConfig},
+    EvtCloseClaimFeeOperator, PoolError,
+};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -15,8 +19,11 @@ pub struct CloseClaimFeeOperatorCtx<'info> {
     #[account(mut)]
     pub rent_receiver: UncheckedAccount<'info>,
 
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
     #[account(
-        constraint = assert_eq_admin(admin.key()) @ PoolError::InvalidAdmin,
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
     )]
     pub admin: Signer<'info>,
 }
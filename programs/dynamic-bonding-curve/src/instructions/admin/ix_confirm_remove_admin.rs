@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{const_pda, state::ProtocolConfig, EvtRemoveAdmin, PoolError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfirmRemoveAdminCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// confirm the pending admin removal, the admin must not be the one who proposed it
+pub fn handle_confirm_remove_admin(ctx: Context<ConfirmRemoveAdminCtx>) -> Result<()> {
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    let removed_admin = protocol_config.confirm_remove_admin(ctx.accounts.admin.key())?;
+
+    emit_cpi!(EvtRemoveAdmin {
+        admin: ctx.accounts.admin.key(),
+        removed_admin,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    assert_eq_admin, constants::seeds::CLAIM_FEE_OPERATOR_PREFIX, state::ClaimFeeOperator,
+    const_pda,
+    constants::seeds::CLAIM_FEE_OPERATOR_PREFIX,
+    state::{ClaimFeeOperator, ProtocolConfig},
     EvtCreateClaimFeeOperator, PoolError,
 };
 
@@ -23,9 +25,12 @@ pub struct CreateClaimFeeOperatorCtx<'info> {
     /// CHECK: operator
     pub operator: UncheckedAccount<'info>,
 
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
     #[account(
         mut,
-        constraint = assert_eq_admin(admin.key()) @ PoolError::InvalidAdmin,
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
     )]
     pub admin: Signer<'info>,
 
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    const_pda,
+    constants::seeds::GLOBAL_STATE_PREFIX,
+    state::{GlobalState, ProtocolConfig},
+    EvtCreateGlobalState, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateGlobalStateCtx<'info> {
+    #[account(
+        init,
+        payer = admin,
+        seeds = [GLOBAL_STATE_PREFIX],
+        bump,
+        space = 8 + GlobalState::INIT_SPACE
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_global_state(ctx: Context<CreateGlobalStateCtx>) -> Result<()> {
+    ctx.accounts.global_state.load_init()?;
+
+    emit_cpi!(EvtCreateGlobalState {
+        global_state: ctx.accounts.global_state.key(),
+    });
+
+    Ok(())
+}
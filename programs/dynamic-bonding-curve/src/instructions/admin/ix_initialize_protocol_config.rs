@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::PROTOCOL_CONFIG_PREFIX, program::DynamicBondingCurve, state::ProtocolConfig,
+    EvtInitializeProtocolConfig, PoolError,
+};
+
+/// Accounts for program upgrade authority to set the first admin
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProtocolConfigCtx<'info> {
+    #[account(
+        init,
+        payer = signer,
+        seeds = [PROTOCOL_CONFIG_PREFIX],
+        bump,
+        space = 8 + ProtocolConfig::INIT_SPACE
+    )]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = dynamic_bonding_curve_program.programdata_address()? == Some(program_data.key()) @ PoolError::InvalidAccount,
+    )]
+    pub dynamic_bonding_curve_program: Program<'info, DynamicBondingCurve>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// CHECK: the first admin
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_protocol_config(ctx: Context<InitializeProtocolConfigCtx>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() != Pubkey::default(),
+        PoolError::InvalidAdmin
+    );
+    let mut protocol_config = ctx.accounts.protocol_config.load_init()?;
+    protocol_config.initialize(ctx.accounts.admin.key());
+
+    emit_cpi!(EvtInitializeProtocolConfig {
+        admin: ctx.accounts.admin.key(),
+    });
+
+    Ok(())
+}
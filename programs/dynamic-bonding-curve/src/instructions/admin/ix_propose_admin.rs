@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{const_pda, state::ProtocolConfig, EvtProposeAdmin, PoolError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdminCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// propose a new admin, the new admin replaces `replaced_admin` on acceptance if it is specified,
+/// only the signer itself can be replaced
+pub fn handle_propose_admin(
+    ctx: Context<ProposeAdminCtx>,
+    new_admin: Pubkey,
+    replaced_admin: Option<Pubkey>,
+) -> Result<()> {
+    let replaced_admin = replaced_admin.unwrap_or_default();
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    protocol_config.propose_admin(ctx.accounts.admin.key(), new_admin, replaced_admin)?;
+
+    emit_cpi!(EvtProposeAdmin {
+        admin: ctx.accounts.admin.key(),
+        new_admin,
+        replaced_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{const_pda, state::ProtocolConfig, EvtProposeRemoveAdmin, PoolError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeRemoveAdminCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// propose to remove an admin, another admin must confirm the removal
+pub fn handle_propose_remove_admin(
+    ctx: Context<ProposeRemoveAdminCtx>,
+    removed_admin: Pubkey,
+) -> Result<()> {
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    protocol_config.propose_remove_admin(ctx.accounts.admin.key(), removed_admin)?;
+
+    emit_cpi!(EvtProposeRemoveAdmin {
+        admin: ctx.accounts.admin.key(),
+        removed_admin,
+    });
+
+    Ok(())
+}
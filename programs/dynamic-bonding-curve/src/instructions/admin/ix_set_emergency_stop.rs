@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    const_pda,
+    state::{GlobalState, ProtocolConfig},
+    EvtSetEmergencyStop, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEmergencyStopCtx<'info> {
+    #[account(mut, address = const_pda::global_state::ID)]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handle_set_emergency_stop(
+    ctx: Context<SetEmergencyStopCtx>,
+    is_emergency_stopped: bool,
+) -> Result<()> {
+    let mut global_state = ctx.accounts.global_state.load_mut()?;
+    global_state.set_emergency_stopped(is_emergency_stopped);
+
+    emit_cpi!(EvtSetEmergencyStop {
+        admin: ctx.accounts.admin.key(),
+        is_emergency_stopped,
+    });
+
+    Ok(())
+}
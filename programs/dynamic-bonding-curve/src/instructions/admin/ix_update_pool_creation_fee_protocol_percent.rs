@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{const_pda, state::ProtocolConfig, EvtUpdatePoolCreationFeeProtocolPercent, PoolError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoolCreationFeeProtocolPercentCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handle_update_pool_creation_fee_protocol_percent(
+    ctx: Context<UpdatePoolCreationFeeProtocolPercentCtx>,
+    percent: u8,
+) -> Result<()> {
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    protocol_config.update_pool_creation_fee_protocol_percent(percent)?;
+
+    emit_cpi!(EvtUpdatePoolCreationFeeProtocolPercent {
+        admin: ctx.accounts.admin.key(),
+        percent,
+    });
+
+    Ok(())
+}
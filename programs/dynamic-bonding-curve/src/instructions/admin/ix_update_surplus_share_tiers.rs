@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    const_pda, constants::MAX_SURPLUS_SHARE_TIER, state::ProtocolConfig,
+    EvtUpdateSurplusShareTiers, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateSurplusShareTiersCtx<'info> {
+    #[account(mut, address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = protocol_config.load()?.is_admin(&admin.key()) @ PoolError::InvalidAdmin,
+    )]
+    pub admin: Signer<'info>,
+}
+
+pub fn handle_update_surplus_share_tiers(
+    ctx: Context<UpdateSurplusShareTiersCtx>,
+    surplus_share_tiers: [u8; MAX_SURPLUS_SHARE_TIER],
+) -> Result<()> {
+    let mut protocol_config = ctx.accounts.protocol_config.load_mut()?;
+    protocol_config.update_surplus_share_tiers(surplus_share_tiers)?;
+
+    emit_cpi!(EvtUpdateSurplusShareTiers {
+        admin: ctx.accounts.admin.key(),
+        surplus_share_tiers,
+    });
+
+    Ok(())
+}
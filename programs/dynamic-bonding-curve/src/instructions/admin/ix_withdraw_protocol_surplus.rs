@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     const_pda,
-    state::{PoolConfig, VirtualPool},
+    state::{get_pool_config, PoolConfig, VirtualPool},
     token::transfer_from_pool,
     treasury, EvtProtocolWithdrawSurplus, PoolError,
 };
@@ -48,12 +48,16 @@ pub struct ProtocolWithdrawSurplusCtx<'info> {
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_protocol_withdraw_surplus(ctx: Context<ProtocolWithdrawSurplusCtx>) -> Result<()> {
+pub fn handle_protocol_withdraw_surplus<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ProtocolWithdrawSurplusCtx<'info>>,
+) -> Result<()> {
     let config = ctx.accounts.config.load()?;
     let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
+    let migration_quote_threshold = pool.get_migration_quote_threshold(&config);
     // Make sure pool has been completed
     require!(
-        pool.is_curve_complete(config.migration_quote_threshold),
+        pool.is_curve_complete(migration_quote_threshold),
         PoolError::NotPermitToDoThisAction
     );
 
@@ -63,7 +67,7 @@ pub fn handle_protocol_withdraw_surplus(ctx: Context<ProtocolWithdrawSurplusCtx>
         PoolError::SurplusHasBeenWithdraw
     );
 
-    let protocol_surplus_amount = pool.get_protocol_surplus(config.migration_quote_threshold)?;
+    let protocol_surplus_amount = pool.get_protocol_surplus(&config, migration_quote_threshold)?;
 
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
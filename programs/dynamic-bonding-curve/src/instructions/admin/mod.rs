@@ -8,3 +8,21 @@ pub mod ix_close_claim_protocol_fee_operator;
 pub use ix_close_claim_protocol_fee_operator::*;
 pub mod ix_withdraw_protocol_surplus;
 pub use ix_withdraw_protocol_surplus::*;
+pub mod ix_create_global_state;
+pub use ix_create_global_state::*;
+pub mod ix_set_emergency_stop;
+pub use ix_set_emergency_stop::*;
+pub mod ix_initialize_protocol_config;
+pub use ix_initialize_protocol_config::*;
+pub mod ix_propose_admin;
+pub use ix_propose_admin::*;
+pub mod ix_accept_admin;
+pub use ix_accept_admin::*;
+pub mod ix_propose_remove_admin;
+pub use ix_propose_remove_admin::*;
+pub mod ix_confirm_remove_admin;
+pub use ix_confirm_remove_admin::*;
+pub mod ix_update_surplus_share_tiers;
+pub use ix_update_surplus_share_tiers::*;
+pub mod ix_update_pool_creation_fee_protocol_percent;
+pub use ix_update_pool_creation_fee_protocol_percent::*;
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    state::{CreatorFeeSplit, VirtualPool},
+    token::{calculate_transfer_fee_excluded_amount, get_epoch_transfer_fee},
+    PoolError,
+};
+
+/// if pool has creator fee split, creator revenue must be sent to creator fee split vaults and credited to its co-creators,
+/// co-creators are credited with the amounts vaults receive after transfer fees, receivers are passed with their mints
+pub fn route_creator_fee_to_creator_fee_split<'info>(
+    pool_key: &Pubkey,
+    pool: &VirtualPool,
+    creator_fee_split: &Option<AccountLoader<'info, CreatorFeeSplit>>,
+    base_receiver: Option<(
+        &InterfaceAccount<'info, Mint>,
+        &InterfaceAccount<'info, TokenAccount>,
+    )>,
+    quote_receiver: (
+        &InterfaceAccount<'info, Mint>,
+        &InterfaceAccount<'info, TokenAccount>,
+    ),
+    base_amount: u64,
+    quote_amount: u64,
+) -> Result<()> {
+    if !pool.has_creator_fee_split() {
+        return Ok(());
+    }
+    let (quote_mint, quote_receiver_token_account) = quote_receiver;
+
+    let mut creator_fee_split = creator_fee_split
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?
+        .load_mut()?;
+    require!(
+        creator_fee_split.pool.eq(pool_key)
+            && creator_fee_split
+                .quote_vault
+                .eq(&quote_receiver_token_account.key())
+            && base_receiver
+                .is_none_or(|(_, account)| creator_fee_split.base_vault.eq(&account.key())),
+        PoolError::InvalidAccount
+    );
+    let transfer_fee_excluded_base_amount = match base_receiver {
+        Some((base_mint, _)) => {
+            calculate_transfer_fee_excluded_amount(
+                get_epoch_transfer_fee(&base_mint.to_account_info())?.as_ref(),
+                base_amount,
+            )?
+            .amount
+        }
+        None => base_amount,
+    };
+    let transfer_fee_excluded_quote_amount = calculate_transfer_fee_excluded_amount(
+        get_epoch_transfer_fee(&quote_mint.to_account_info())?.as_ref(),
+        quote_amount,
+    )?
+    .amount;
+    creator_fee_split.distribute(
+        transfer_fee_excluded_base_amount,
+        transfer_fee_excluded_quote_amount,
+    )
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda, state::CreatorFeeSplit, token::transfer_from_pool, EvtClaimCreatorFeeSplit,
+};
+
+/// Accounts for co-creator to claim its share of creator revenue
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCreatorFeeSplitCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// creator fee split, co-creators can still claim after the pool is closed
+    #[account(
+        mut,
+        has_one = base_vault,
+        has_one = quote_vault,
+    )]
+    pub creator_fee_split: AccountLoader<'info, CreatorFeeSplit>,
+
+    /// The vault token account of creator fee split for base token
+    #[account(mut, token::token_program = token_base_program, token::mint = base_mint)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account of creator fee split for quote token
+    #[account(mut, token::token_program = token_quote_program, token::mint = quote_mint)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The receiver token account for base token
+    #[account(mut)]
+    pub token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The receiver token account for quote token
+    #[account(mut)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of base token
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of quote token
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub co_creator: Signer<'info>,
+
+    /// Token a program
+    pub token_base_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_claim_creator_fee_split(ctx: Context<ClaimCreatorFeeSplitCtx>) -> Result<()> {
+    let (token_base_amount, token_quote_amount) = ctx
+        .accounts
+        .creator_fee_split
+        .load_mut()?
+        .claim(ctx.accounts.co_creator.key)?;
+
+    transfer_from_pool(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.base_mint,
+        &ctx.accounts.base_vault,
+        &ctx.accounts.token_a_account,
+        &ctx.accounts.token_base_program,
+        token_base_amount,
+        const_pda::pool_authority::BUMP,
+    )?;
+
+    transfer_from_pool(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.quote_mint,
+        &ctx.accounts.quote_vault,
+        &ctx.accounts.token_b_account,
+        &ctx.accounts.token_quote_program,
+        token_quote_amount,
+        const_pda::pool_authority::BUMP,
+    )?;
+
+    emit_cpi!(EvtClaimCreatorFeeSplit {
+        creator_fee_split: ctx.accounts.creator_fee_split.key(),
+        co_creator: ctx.accounts.co_creator.key(),
+        token_base_amount,
+        token_quote_amount,
+    });
+    Ok(())
+}
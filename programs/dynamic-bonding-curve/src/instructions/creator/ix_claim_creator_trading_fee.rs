@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{const_pda, state::VirtualPool, token::transfer_from_pool, EvtClaimCreatorTradingFee};
+use crate::{
+    assert_claim_fee_authority, const_pda, route_creator_fee_to_creator_fee_split,
+    state::{CreatorFeeSplit, DelegatedClaimFeeOperator, OperatorScope, VirtualPool},
+    token::transfer_from_pool,
+    EvtClaimCreatorTradingFee,
+};
 
 /// Accounts for creator to claim trading fees
 #[event_cpi]
@@ -18,7 +23,6 @@ pub struct ClaimCreatorTradingFeesCtx<'info> {
         has_one = base_vault,
         has_one = quote_vault,
         has_one = base_mint,
-        has_one = creator,
     )]
     pub pool: AccountLoader<'info, VirtualPool>,
 
@@ -44,7 +48,15 @@ pub struct ClaimCreatorTradingFeesCtx<'info> {
     /// The mint of token b
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub creator: Signer<'info>,
+    /// creator or its delegated operator
+    pub signer: Signer<'info>,
+
+    /// delegated operator of creator, only required if signer is not creator
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// creator fee split of pool, only required if pool has creator fee split
+    #[account(mut)]
+    pub creator_fee_split: Option<AccountLoader<'info, CreatorFeeSplit>>,
 
     /// Token a program
     pub token_base_program: Interface<'info, TokenInterface>,
@@ -53,16 +65,41 @@ pub struct ClaimCreatorTradingFeesCtx<'info> {
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
 
-/// creator claim fees.
+/// creator claim fees, fees are sent to creator fee split vaults if pool has creator fee split.
 pub fn handle_claim_creator_trading_fee(
     ctx: Context<ClaimCreatorTradingFeesCtx>,
     max_base_amount: u64,
     max_quote_amount: u64,
 ) -> Result<()> {
     let mut pool = ctx.accounts.pool.load_mut()?;
+    // creator fee split vaults are validated when routing fees
+    let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] =
+        if pool.has_creator_fee_split() {
+            &[]
+        } else {
+            &[&ctx.accounts.token_a_account, &ctx.accounts.token_b_account]
+        };
+    assert_claim_fee_authority(
+        &pool.creator,
+        OperatorScope::Creator,
+        ctx.accounts.signer.key,
+        &ctx.accounts.delegated_operator,
+        receiver_token_accounts,
+    )?;
+
     let (token_base_amount, token_quote_amount) =
         pool.claim_creator_trading_fee(max_base_amount, max_quote_amount)?;
 
+    route_creator_fee_to_creator_fee_split(
+        &ctx.accounts.pool.key(),
+        &pool,
+        &ctx.accounts.creator_fee_split,
+        Some((&ctx.accounts.base_mint, &ctx.accounts.token_a_account)),
+        (&ctx.accounts.quote_mint, &ctx.accounts.token_b_account),
+        token_base_amount,
+        token_quote_amount,
+    )?;
+
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
         &ctx.accounts.base_mint,
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    constants::seeds::{CREATOR_FEE_SPLIT_PREFIX, CREATOR_FEE_SPLIT_VAULT_PREFIX},
+    state::{CreatorFeeSplit, PoolConfig, VirtualPool},
+    EvtCreateCreatorFeeSplit, FeeSplitRecipientParams,
+};
+
+/// Accounts for creator to split creator revenue of a pool among many co-creators
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCreatorFeeSplitCtx<'info> {
+    #[account(has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(mut, has_one = creator, has_one = config, has_one = base_mint)]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            CREATOR_FEE_SPLIT_PREFIX,
+            virtual_pool.key().as_ref(),
+        ],
+        bump,
+        space = 8 + CreatorFeeSplit::INIT_SPACE
+    )]
+    pub creator_fee_split: AccountLoader<'info, CreatorFeeSplit>,
+
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Vault holding base token of co-creators
+    #[account(
+        init,
+        seeds = [
+            CREATOR_FEE_SPLIT_VAULT_PREFIX,
+            base_mint.key().as_ref(),
+            creator_fee_split.key().as_ref(),
+        ],
+        token::mint = base_mint,
+        token::authority = pool_authority,
+        token::token_program = token_base_program,
+        payer = payer,
+        bump,
+    )]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault holding quote token of co-creators
+    #[account(
+        init,
+        seeds = [
+            CREATOR_FEE_SPLIT_VAULT_PREFIX,
+            quote_mint.key().as_ref(),
+            creator_fee_split.key().as_ref(),
+        ],
+        token::mint = quote_mint,
+        token::authority = pool_authority,
+        token::token_program = token_quote_program,
+        payer = payer,
+        bump,
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of base token
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of quote token
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token a program
+    pub token_base_program: Interface<'info, TokenInterface>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_creator_fee_split(
+    ctx: Context<CreateCreatorFeeSplitCtx>,
+    co_creators: Vec<FeeSplitRecipientParams>,
+) -> Result<()> {
+    let mut creator_fee_split = ctx.accounts.creator_fee_split.load_init()?;
+    creator_fee_split.initialize(
+        ctx.accounts.virtual_pool.key(),
+        ctx.accounts.base_vault.key(),
+        ctx.accounts.quote_vault.key(),
+        &co_creators,
+    )?;
+
+    // creator revenue, including fees that have not been claimed, is routed to creator fee split vaults from now on
+    let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    pool.creator_fee_split_flag = 1;
+
+    emit_cpi!(EvtCreateCreatorFeeSplit {
+        pool: ctx.accounts.virtual_pool.key(),
+        creator_fee_split: ctx.accounts.creator_fee_split.key(),
+        co_creators,
+    });
+    Ok(())
+}
@@ -2,33 +2,84 @@ use anchor_lang::prelude::*;
 
 use crate::{
     state::{MigrationOption, MigrationProgress, PoolConfig, VirtualPool},
-    EvtUpdatePoolCreator, MeteoraDammMigrationMetadata, PoolError,
+    EvtCancelPoolCreator, EvtProposePoolCreator, EvtUpdatePoolCreator,
+    MeteoraDammMigrationMetadata, PoolError,
 };
 
-/// Accounts for transfer pool creator
+/// Accounts for creator to propose a new pool creator
 #[event_cpi]
 #[derive(Accounts)]
-pub struct TransferPoolCreatorCtx<'info> {
+pub struct ProposePoolCreatorCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
+    pub creator: Signer<'info>,
+
+    /// CHECK: new creator address, can be anything except old creator
+    #[account(
+        constraint = new_creator.key().ne(creator.key) @ PoolError::InvalidNewCreator,
+        constraint = new_creator.key().ne(&Pubkey::default()) @ PoolError::InvalidNewCreator,
+    )]
+    pub new_creator: UncheckedAccount<'info>,
+}
+
+/// Accounts for pending creator to accept the pool
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptPoolCreatorCtx<'info> {
     #[account(
         mut,
-        has_one = creator,
         has_one = config,
+        constraint = virtual_pool.load()?.pending_creator == pending_creator.key() @ PoolError::InvalidNewCreator,
     )]
     pub virtual_pool: AccountLoader<'info, VirtualPool>,
 
     pub config: AccountLoader<'info, PoolConfig>,
 
+    pub pending_creator: Signer<'info>,
+}
+
+/// Accounts for creator to cancel the pending transfer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelPoolCreatorCtx<'info> {
+    #[account(mut, has_one = creator)]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
     pub creator: Signer<'info>,
+}
 
-    /// CHECK: new creator address, can be anything except old creator
-    #[account(
-        constraint = new_creator.key().ne(creator.key) @ PoolError::InvalidNewCreator,
-    )]
-    pub new_creator: UncheckedAccount<'info>,
+pub fn handle_propose_pool_creator(ctx: Context<ProposePoolCreatorCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    pool.pending_creator = ctx.accounts.new_creator.key();
+
+    emit_cpi!(EvtProposePoolCreator {
+        pool: ctx.accounts.virtual_pool.key(),
+        creator: ctx.accounts.creator.key(),
+        new_creator: ctx.accounts.new_creator.key(),
+    });
+    Ok(())
 }
 
-pub fn handle_transfer_pool_creator<'c: 'info, 'info>(
-    ctx: Context<'_, '_, 'c, 'info, TransferPoolCreatorCtx>,
+pub fn handle_cancel_pool_creator(ctx: Context<CancelPoolCreatorCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    require!(
+        pool.has_pending_creator(),
+        PoolError::NotPermitToDoThisAction
+    );
+    let pending_creator = pool.pending_creator;
+    pool.pending_creator = Pubkey::default();
+
+    emit_cpi!(EvtCancelPoolCreator {
+        pool: ctx.accounts.virtual_pool.key(),
+        creator: ctx.accounts.creator.key(),
+        pending_creator,
+    });
+    Ok(())
+}
+
+pub fn handle_accept_pool_creator<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AcceptPoolCreatorCtx>,
 ) -> Result<()> {
     let mut pool = ctx.accounts.virtual_pool.load_mut()?;
 
@@ -81,12 +132,14 @@ pub fn handle_transfer_pool_creator<'c: 'info, 'info>(
         _ => return Err(PoolError::NotPermitToDoThisAction.into()),
     }
 
-    pool.creator = ctx.accounts.new_creator.key();
+    let creator = pool.creator;
+    pool.creator = ctx.accounts.pending_creator.key();
+    pool.pending_creator = Pubkey::default();
 
     emit_cpi!(EvtUpdatePoolCreator {
         pool: ctx.accounts.virtual_pool.key(),
-        creator: ctx.accounts.creator.key(),
-        new_creator: ctx.accounts.new_creator.key(),
+        creator,
+        new_creator: ctx.accounts.pending_creator.key(),
     });
     Ok(())
 }
@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda,
-    state::{PoolConfig, VirtualPool},
+    assert_claim_fee_authority, const_pda, route_creator_fee_to_creator_fee_split,
+    state::{
+        get_pool_config, CreatorFeeSplit, DelegatedClaimFeeOperator, OperatorScope, PoolConfig,
+        VirtualPool,
+    },
     token::transfer_from_pool,
     EvtCreatorWithdrawSurplus, PoolError,
 };
@@ -25,7 +28,6 @@ pub struct CreatorWithdrawSurplusCtx<'info> {
         mut,
         has_one = quote_vault,
         has_one = config,
-        has_one = creator,
     )]
     pub virtual_pool: AccountLoader<'info, VirtualPool>,
 
@@ -40,19 +42,45 @@ pub struct CreatorWithdrawSurplusCtx<'info> {
     /// The mint of quote token
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub creator: Signer<'info>,
+    /// creator or its delegated operator
+    pub signer: Signer<'info>,
+
+    /// delegated operator of creator, only required if signer is not creator
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// creator fee split of pool, only required if pool has creator fee split
+    #[account(mut)]
+    pub creator_fee_split: Option<AccountLoader<'info, CreatorFeeSplit>>,
 
     /// Token b program
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_creator_withdraw_surplus(ctx: Context<CreatorWithdrawSurplusCtx>) -> Result<()> {
+pub fn handle_creator_withdraw_surplus<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CreatorWithdrawSurplusCtx<'info>>,
+) -> Result<()> {
     let config = ctx.accounts.config.load()?;
     let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
+    // creator fee split vault is validated when routing surplus
+    let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] =
+        if pool.has_creator_fee_split() {
+            &[]
+        } else {
+            &[&ctx.accounts.token_quote_account]
+        };
+    assert_claim_fee_authority(
+        &pool.creator,
+        OperatorScope::Creator,
+        ctx.accounts.signer.key,
+        &ctx.accounts.delegated_operator,
+        receiver_token_accounts,
+    )?;
 
+    let migration_quote_threshold = pool.get_migration_quote_threshold(&config);
     // Make sure pool has been completed
     require!(
-        pool.is_curve_complete(config.migration_quote_threshold),
+        pool.is_curve_complete(migration_quote_threshold),
         PoolError::NotPermitToDoThisAction
     );
 
@@ -61,9 +89,19 @@ pub fn handle_creator_withdraw_surplus(ctx: Context<CreatorWithdrawSurplusCtx>)
         pool.is_creator_withdraw_surplus == 0,
         PoolError::SurplusHasBeenWithdraw
     );
-    let total_surplus = pool.get_total_surplus(config.migration_quote_threshold)?;
+    let total_surplus = pool.get_total_surplus(migration_quote_threshold)?;
     let creator_surplus_amount = pool.get_creator_surplus(&config, total_surplus)?;
 
+    route_creator_fee_to_creator_fee_split(
+        &ctx.accounts.virtual_pool.key(),
+        &pool,
+        &ctx.accounts.creator_fee_split,
+        None,
+        (&ctx.accounts.quote_mint, &ctx.accounts.token_quote_account),
+        0,
+        creator_surplus_amount,
+    )?;
+
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
         &ctx.accounts.quote_mint,
@@ -6,3 +6,9 @@ pub mod ix_withdraw_creator_surplus;
 pub use ix_withdraw_creator_surplus::*;
 pub mod ix_transfer_pool_creator;
 pub use ix_transfer_pool_creator::*;
+pub mod creator_fee_split_route;
+pub use creator_fee_split_route::*;
+pub mod ix_create_creator_fee_split;
+pub use ix_create_creator_fee_split::*;
+pub mod ix_claim_creator_fee_split;
+pub use ix_claim_creator_fee_split::*;
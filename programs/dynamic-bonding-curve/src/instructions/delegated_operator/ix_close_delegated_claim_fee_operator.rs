@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::DelegatedClaimFeeOperator, EvtCloseDelegatedClaimFeeOperator};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseDelegatedClaimFeeOperatorCtx<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+    )]
+    pub delegated_operator: AccountLoader<'info, DelegatedClaimFeeOperator>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_close_delegated_claim_fee_operator(
+    ctx: Context<CloseDelegatedClaimFeeOperatorCtx>,
+) -> Result<()> {
+    let delegated_operator = ctx.accounts.delegated_operator.load()?;
+    emit_cpi!(EvtCloseDelegatedClaimFeeOperator {
+        delegated_operator: ctx.accounts.delegated_operator.key(),
+        owner: delegated_operator.owner,
+        operator: delegated_operator.operator,
+        scope: delegated_operator.scope,
+    });
+
+    Ok(())
+}
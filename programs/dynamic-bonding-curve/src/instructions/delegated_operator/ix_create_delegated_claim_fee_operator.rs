@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::DELEGATED_CLAIM_FEE_OPERATOR_PREFIX,
+    state::{DelegatedClaimFeeOperator, OperatorScope},
+    EvtCreateDelegatedClaimFeeOperator, PoolError,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(scope: u8)]
+pub struct CreateDelegatedClaimFeeOperatorCtx<'info> {
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            DELEGATED_CLAIM_FEE_OPERATOR_PREFIX,
+            &[scope],
+            owner.key().as_ref(),
+            operator.key().as_ref(),
+        ],
+        bump,
+        space = 8 + DelegatedClaimFeeOperator::INIT_SPACE
+    )]
+    pub delegated_operator: AccountLoader<'info, DelegatedClaimFeeOperator>,
+
+    /// CHECK: operator
+    #[account(
+        constraint = operator.key().ne(owner.key) @ PoolError::InvalidAccount,
+    )]
+    pub operator: UncheckedAccount<'info>,
+
+    /// fee claimer of configs or creator of pools
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_delegated_claim_fee_operator(
+    ctx: Context<CreateDelegatedClaimFeeOperatorCtx>,
+    scope: u8,
+) -> Result<()> {
+    let operator_scope =
+        OperatorScope::try_from(scope).map_err(|_| PoolError::InvalidOperatorScope)?;
+
+    let mut delegated_operator = ctx.accounts.delegated_operator.load_init()?;
+    delegated_operator.initialize(
+        ctx.accounts.owner.key(),
+        ctx.accounts.operator.key(),
+        operator_scope,
+    );
+
+    emit_cpi!(EvtCreateDelegatedClaimFeeOperator {
+        delegated_operator: ctx.accounts.delegated_operator.key(),
+        owner: ctx.accounts.owner.key(),
+        operator: ctx.accounts.operator.key(),
+        scope,
+    });
+
+    Ok(())
+}
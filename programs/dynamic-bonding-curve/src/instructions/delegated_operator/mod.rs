@@ -0,0 +1,6 @@
+pub mod operator_auth;
+pub use operator_auth::*;
+pub mod ix_create_delegated_claim_fee_operator;
+pub use ix_create_delegated_claim_fee_operator::*;
+pub mod ix_close_delegated_claim_fee_operator;
+pub use ix_close_delegated_claim_fee_operator::*;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    state::{DelegatedClaimFeeOperator, OperatorScope},
+    PoolError,
+};
+
+/// signer must be the owner or its delegated operator, operator can only send funds to owner token accounts
+pub fn assert_claim_fee_authority<'info>(
+    owner: &Pubkey,
+    scope: OperatorScope,
+    signer: &Pubkey,
+    delegated_operator: &Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+    receiver_token_accounts: &[&InterfaceAccount<'info, TokenAccount>],
+) -> Result<()> {
+    if signer.eq(owner) {
+        return Ok(());
+    }
+
+    let delegated_operator = delegated_operator
+        .as_ref()
+        .ok_or(PoolError::NotPermitToDoThisAction)?
+        .load()?;
+    require!(
+        delegated_operator.is_delegated(owner, scope, signer),
+        PoolError::NotPermitToDoThisAction
+    );
+
+    for receiver_token_account in receiver_token_accounts {
+        require!(
+            receiver_token_account.owner.eq(owner),
+            PoolError::InvalidAccount
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    activation_handler::get_current_point,
+    const_pda,
+    constants::seeds::{POOL_PREFIX, TOKEN_VAULT_PREFIX},
+    max_key, min_key, process_collect_pool_creation_fee, process_verify_pool_creation_approval,
+    state::{
+        fee::VolatilityTracker, get_config_with_curve_variant, FeeSplit, GlobalState, PoolConfig,
+        PoolType, TokenType, VirtualPool,
+    },
+    token::{
+        get_token_program_flags, is_supported_base_mint, transfer_from_user, TokenProgramFlags,
+    },
+    treasury, EvtInitializePool, PoolError, ProcessCollectPoolCreationFeeParams,
+    ProcessVerifyPoolCreationApprovalParams,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePoolWithExistingMintParameters {
+    /// 0 means curve of config, otherwise 1-based index of curve variant in curve variants account
+    pub curve_variant: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeVirtualPoolWithExistingMintCtx<'info> {
+    /// Which config the pool belongs to.
+    #[account(mut, has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: AccountInfo<'info>,
+
+    pub creator: Signer<'info>,
+
+    /// Existing base mint, creator deposits the initial base supply of the config
+    #[account(
+        mint::token_program = token_base_program,
+    )]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mint::token_program = token_quote_program,
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Initialize an account to store the pool state
+    #[account(
+        init,
+        seeds = [
+            POOL_PREFIX,
+            config.key().as_ref(),
+            &max_key(&base_mint.key(), &quote_mint.key()),
+            &min_key(&base_mint.key(), &quote_mint.key()),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VirtualPool::INIT_SPACE
+    )]
+    pub pool: AccountLoader<'info, VirtualPool>,
+
+    /// Token a vault for the pool
+    #[account(
+        init,
+        seeds = [
+            TOKEN_VAULT_PREFIX,
+            base_mint.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        token::mint = base_mint,
+        token::authority = pool_authority,
+        token::token_program = token_base_program,
+        payer = payer,
+        bump,
+    )]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token b vault for the pool
+    #[account(
+        init,
+        seeds = [
+            TOKEN_VAULT_PREFIX,
+            quote_mint.key().as_ref(),
+            pool.key().as_ref(),
+        ],
+        token::mint = quote_mint,
+        token::authority = pool_authority,
+        token::token_program = token_quote_program,
+        payer = payer,
+        bump,
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// creator base token account, the initial base supply is transferred from it
+    #[account(mut)]
+    pub creator_token_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Address paying to create the pool. Can be anyone
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Program of the base mint
+    pub token_base_program: Interface<'info, TokenInterface>,
+
+    /// Program of the quote mint
+    pub token_quote_program: Interface<'info, TokenInterface>,
+
+    // Sysvar for program account
+    pub system_program: Program<'info, System>,
+
+    /// global state, instruction is rejected when emergency stop is on
+    #[account(
+        address = const_pda::global_state::ID,
+        constraint = !global_state.load()?.is_emergency_stopped() @ PoolError::EmergencyStopped,
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    /// payer quote token account, only required if pool creation fee is paid in quote token
+    #[account(mut)]
+    pub payer_token_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: fee claimer of config, only required if pool creation fee is paid in SOL
+    #[account(mut)]
+    pub partner: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: protocol treasury, only required if pool creation fee is paid in SOL with protocol cut
+    #[account(mut, address = treasury::ID)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// pool creator authority signer, not required if approval is verified by ed25519 instruction
+    pub pool_creator_authority_signer: Option<Signer<'info>>,
+
+    /// CHECK: instructions sysvar, only required if approval is verified by ed25519 instruction
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// fee split of config, only required if pool creation fee is paid in SOL and config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
+}
+
+pub fn handle_initialize_virtual_pool_with_existing_mint<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVirtualPoolWithExistingMintCtx<'info>>,
+    params: InitializePoolWithExistingMintParameters,
+) -> Result<()> {
+    let InitializePoolWithExistingMintParameters { curve_variant } = params;
+
+    let mut config = ctx.accounts.config.load_mut()?;
+    process_verify_pool_creation_approval(ProcessVerifyPoolCreationApprovalParams {
+        config_key: ctx.accounts.config.key(),
+        config: &config,
+        creator: ctx.accounts.creator.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        pool_creator_authority_signer: ctx.accounts.pool_creator_authority_signer.as_ref(),
+        instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+    })?;
+    // pool count tells whether config can be fully updated or closed
+    config.increase_pool_count()?;
+    // creator picks curve of config or one of its curve variants
+    let config = get_config_with_curve_variant(
+        &ctx.accounts.config.key(),
+        &config,
+        curve_variant,
+        ctx.remaining_accounts,
+    )?;
+
+    let pool_type = validate_existing_base_mint(
+        &config,
+        &ctx.accounts.base_mint,
+        &ctx.accounts.quote_mint.key(),
+    )?;
+
+    // creator deposits exactly the initial base supply, mint authority of the base mint is not touched
+    let initial_base_supply = config.get_initial_base_supply()?;
+    transfer_from_user(
+        &ctx.accounts.creator,
+        &ctx.accounts.base_mint,
+        &ctx.accounts.creator_token_base_account,
+        &ctx.accounts.base_vault,
+        &ctx.accounts.token_base_program,
+        initial_base_supply,
+    )?;
+
+    // init pool
+    let mut pool = ctx.accounts.pool.load_init()?;
+
+    let activation_point = get_current_point(config.activation_type)?;
+
+    pool.initialize(
+        VolatilityTracker::default(),
+        ctx.accounts.config.key(),
+        ctx.accounts.creator.key(),
+        ctx.accounts.base_mint.key(),
+        ctx.accounts.base_vault.key(),
+        ctx.accounts.quote_vault.key(),
+        config.sqrt_start_price,
+        pool_type.into(),
+        activation_point,
+        initial_base_supply,
+    );
+    pool.curve_variant = curve_variant;
+
+    process_collect_pool_creation_fee(ProcessCollectPoolCreationFeeParams {
+        config: &config,
+        pool: &mut pool,
+        payer: &ctx.accounts.payer,
+        quote_mint: &ctx.accounts.quote_mint,
+        quote_vault: &ctx.accounts.quote_vault,
+        token_quote_program: &ctx.accounts.token_quote_program,
+        system_program: &ctx.accounts.system_program,
+        payer_token_quote_account: ctx.accounts.payer_token_quote_account.as_deref(),
+        partner: ctx.accounts.partner.as_ref(),
+        treasury: ctx.accounts.treasury.as_ref(),
+        fee_split: ctx.accounts.fee_split.as_ref(),
+    })?;
+
+    emit_cpi!(EvtInitializePool {
+        pool: ctx.accounts.pool.key(),
+        config: ctx.accounts.config.key(),
+        creator: ctx.accounts.creator.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        pool_type: pool_type.into(),
+        activation_point,
+        pool_creation_fee: config.pool_fees.pool_creation_fee,
+        pool_creation_fee_token: config.pool_fees.pool_creation_fee_token,
+        curve_variant,
+    });
+    Ok(())
+}
+
+/// base mint must match token type and decimals of config, and its transfers can't be restricted by a freeze authority or extensions
+pub fn validate_existing_base_mint(
+    config: &PoolConfig,
+    base_mint: &InterfaceAccount<Mint>,
+    quote_mint: &Pubkey,
+) -> Result<PoolType> {
+    let token_type_value =
+        TokenType::try_from(config.token_type).map_err(|_| PoolError::InvalidTokenType)?;
+    let pool_type = match (token_type_value, get_token_program_flags(base_mint)) {
+        (TokenType::SplToken, TokenProgramFlags::TokenProgram) => PoolType::SplToken,
+        (TokenType::Token2022, TokenProgramFlags::TokenProgram2022) => PoolType::Token2022,
+        _ => return Err(PoolError::InvalidTokenType.into()),
+    };
+    require!(
+        base_mint.decimals == config.token_decimal,
+        PoolError::InvalidTokenDecimals
+    );
+    require!(
+        base_mint.key() != *quote_mint && is_supported_base_mint(base_mint)?,
+        PoolError::InvalidBaseMint
+    );
+    // existing base mint can't be frozen, so its transfers can't be restricted until migration
+    require!(
+        !config.is_non_transferable(),
+        PoolError::InvalidNonTransferableOption
+    );
+    Ok(pool_type)
+}
@@ -12,9 +12,14 @@ use crate::{
     activation_handler::get_current_point,
     const_pda,
     constants::seeds::{POOL_PREFIX, TOKEN_VAULT_PREFIX},
-    process_create_token_metadata,
-    state::{fee::VolatilityTracker, PoolConfig, PoolType, TokenType, VirtualPool},
-    EvtInitializePool, PoolError, ProcessCreateTokenMetadataParams,
+    process_collect_pool_creation_fee, process_create_token_metadata,
+    process_verify_pool_creation_approval,
+    state::{
+        fee::VolatilityTracker, get_config_with_curve_variant, get_token_metadata_config, FeeSplit,
+        GlobalState, PoolConfig, PoolType, TokenMetadataConfig, TokenType, VirtualPool,
+    },
+    treasury, EvtInitializePool, PoolError, ProcessCollectPoolCreationFeeParams,
+    ProcessCreateTokenMetadataParams, ProcessVerifyPoolCreationApprovalParams,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,6 +27,8 @@ pub struct InitializePoolParameters {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// 0 means curve of config, otherwise 1-based index of curve variant in curve variants account
+    pub curve_variant: u8,
 }
 
 // To fix IDL generation: https://github.com/coral-xyz/anchor/issues/3209
@@ -37,7 +44,7 @@ pub fn min_key(left: &Pubkey, right: &Pubkey) -> [u8; 32] {
 #[derive(Accounts)]
 pub struct InitializeVirtualPoolWithSplTokenCtx<'info> {
     /// Which config the pool belongs to.
-    #[account(has_one = quote_mint)]
+    #[account(mut, has_one = quote_mint)]
     pub config: AccountLoader<'info, PoolConfig>,
 
     /// CHECK: pool authority
@@ -54,6 +61,7 @@ pub struct InitializeVirtualPoolWithSplTokenCtx<'info> {
         payer = payer,
         mint::decimals = config.load()?.token_decimal,
         mint::authority = pool_authority,
+        mint::freeze_authority = pool_authority,
         mint::token_program = token_program,
     )]
     pub base_mint: Box<Account<'info, Mint>>,
@@ -129,13 +137,69 @@ pub struct InitializeVirtualPoolWithSplTokenCtx<'info> {
 
     // Sysvar for program account
     pub system_program: Program<'info, System>,
+
+    /// global state, instruction is rejected when emergency stop is on
+    #[account(
+        address = const_pda::global_state::ID,
+        constraint = !global_state.load()?.is_emergency_stopped() @ PoolError::EmergencyStopped,
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    /// payer quote token account, only required if pool creation fee is paid in quote token
+    #[account(mut)]
+    pub payer_token_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// CHECK: fee claimer of config, only required if pool creation fee is paid in SOL
+    #[account(mut)]
+    pub partner: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: protocol treasury, only required if pool creation fee is paid in SOL with protocol cut
+    #[account(mut, address = treasury::ID)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// pool creator authority signer, not required if approval is verified by ed25519 instruction
+    pub pool_creator_authority_signer: Option<Signer<'info>>,
+
+    /// CHECK: instructions sysvar, only required if approval is verified by ed25519 instruction
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// token metadata config of config, only required if config has token metadata config
+    pub token_metadata_config: Option<AccountLoader<'info, TokenMetadataConfig>>,
+
+    /// fee split of config, only required if pool creation fee is paid in SOL and config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
 }
 
 pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, InitializeVirtualPoolWithSplTokenCtx<'info>>,
     params: InitializePoolParameters,
 ) -> Result<()> {
-    let config = ctx.accounts.config.load()?;
+    let InitializePoolParameters {
+        name,
+        symbol,
+        uri,
+        curve_variant,
+    } = params;
+
+    let mut config = ctx.accounts.config.load_mut()?;
+    process_verify_pool_creation_approval(ProcessVerifyPoolCreationApprovalParams {
+        config_key: ctx.accounts.config.key(),
+        config: &config,
+        creator: ctx.accounts.creator.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        pool_creator_authority_signer: ctx.accounts.pool_creator_authority_signer.as_ref(),
+        instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+    })?;
+    // pool count tells whether config can be fully updated or closed
+    config.increase_pool_count()?;
+    // creator picks curve of config or one of its curve variants
+    let config = get_config_with_curve_variant(
+        &ctx.accounts.config.key(),
+        &config,
+        curve_variant,
+        ctx.remaining_accounts,
+    )?;
     let initial_base_supply = config.get_initial_base_supply()?;
 
     let token_type_value =
@@ -145,9 +209,12 @@ pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         PoolError::InvalidTokenType
     );
 
-    let InitializePoolParameters { name, symbol, uri } = params;
-
     let token_authority = config.get_token_authority()?;
+    let token_metadata_config = get_token_metadata_config(
+        &ctx.accounts.config.key(),
+        &config,
+        ctx.accounts.token_metadata_config.as_ref(),
+    )?;
     // create token metadata
     process_create_token_metadata(ProcessCreateTokenMetadataParams {
         system_program: ctx.accounts.system_program.to_account_info(),
@@ -163,6 +230,7 @@ pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         pool_authority_bump: const_pda::pool_authority::BUMP,
         token_authority,
         partner: config.fee_claimer,
+        token_metadata_config: token_metadata_config.as_ref(),
     })?;
 
     // mint token
@@ -197,6 +265,22 @@ pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         token_mint_authority,
     )?;
 
+    // pool authority freezes base token accounts outside of swap until migration, otherwise base mint can't be frozen
+    if !config.is_non_transferable() {
+        anchor_spl::token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::SetAuthority {
+                    current_authority: ctx.accounts.pool_authority.to_account_info(),
+                    account_or_mint: ctx.accounts.base_mint.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            AuthorityType::FreezeAccount,
+            None,
+        )?;
+    }
+
     // init pool
     let mut pool = ctx.accounts.pool.load_init()?;
 
@@ -214,6 +298,22 @@ pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         activation_point,
         initial_base_supply,
     );
+    pool.curve_variant = curve_variant;
+    pool.non_transferable_flag = config.non_transferable_flag;
+
+    process_collect_pool_creation_fee(ProcessCollectPoolCreationFeeParams {
+        config: &config,
+        pool: &mut pool,
+        payer: &ctx.accounts.payer,
+        quote_mint: &ctx.accounts.quote_mint,
+        quote_vault: &ctx.accounts.quote_vault,
+        token_quote_program: &ctx.accounts.token_quote_program,
+        system_program: &ctx.accounts.system_program,
+        payer_token_quote_account: ctx.accounts.payer_token_quote_account.as_deref(),
+        partner: ctx.accounts.partner.as_ref(),
+        treasury: ctx.accounts.treasury.as_ref(),
+        fee_split: ctx.accounts.fee_split.as_ref(),
+    })?;
 
     emit_cpi!(EvtInitializePool {
         pool: ctx.accounts.pool.key(),
@@ -222,6 +322,9 @@ pub fn handle_initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         base_mint: ctx.accounts.base_mint.key(),
         pool_type: PoolType::SplToken.into(),
         activation_point,
+        pool_creation_fee: config.pool_fees.pool_creation_fee,
+        pool_creation_fee_token: config.pool_fees.pool_creation_fee_token,
+        curve_variant,
     });
     Ok(())
 }
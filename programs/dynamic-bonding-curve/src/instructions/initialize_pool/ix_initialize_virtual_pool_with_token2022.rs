@@ -4,10 +4,17 @@ use crate::{
     activation_handler::get_current_point,
     const_pda,
     constants::seeds::{POOL_PREFIX, TOKEN_VAULT_PREFIX},
+    process_collect_pool_creation_fee, process_initialize_token2022_base_mint,
+    process_verify_pool_creation_approval,
+    safe_math::SafeMath,
     state::fee::VolatilityTracker,
-    state::{PoolConfig, PoolType, TokenType, VirtualPool},
+    state::{
+        get_config_with_curve_variant, get_token_metadata_config, FeeSplit, GlobalState,
+        PoolConfig, PoolType, TokenMetadataConfig, TokenType, VirtualPool,
+    },
     token::update_account_lamports_to_minimum_balance,
-    EvtInitializePool, PoolError,
+    treasury, EvtInitializePool, PoolError, ProcessCollectPoolCreationFeeParams,
+    ProcessInitializeToken2022BaseMintParams, ProcessVerifyPoolCreationApprovalParams,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
@@ -15,16 +22,17 @@ use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
 use anchor_spl::{
     token_2022::{mint_to, MintTo, Token2022},
     token_interface::{
-        token_metadata_initialize, token_metadata_update_authority, Mint, TokenAccount,
-        TokenInterface, TokenMetadataInitialize,
+        token_metadata_initialize, token_metadata_update_authority, token_metadata_update_field,
+        Mint, TokenAccount, TokenInterface, TokenMetadataInitialize, TokenMetadataUpdateField,
     },
 };
+use spl_token_metadata_interface::state::Field;
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeVirtualPoolWithToken2022Ctx<'info> {
     /// Which config the pool belongs to.
-    #[account(has_one = quote_mint)]
+    #[account(mut, has_one = quote_mint)]
     pub config: AccountLoader<'info, PoolConfig>,
 
     /// CHECK: pool authority
@@ -35,18 +43,9 @@ pub struct InitializeVirtualPoolWithToken2022Ctx<'info> {
 
     pub creator: Signer<'info>,
 
-    /// Unique token mint address, initialize in contract
-    #[account(
-        init,
-        signer,
-        payer = payer,
-        mint::token_program = token_program,
-        mint::decimals = config.load()?.token_decimal,
-        mint::authority = pool_authority,
-        extensions::metadata_pointer::authority = pool_authority,
-        extensions::metadata_pointer::metadata_address = base_mint,
-    )]
-    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Unique token mint address, initialize in contract with extensions of config
+    #[account(mut)]
+    pub base_mint: Signer<'info>,
 
     #[account(
         mint::token_program = token_quote_program,
@@ -68,21 +67,17 @@ pub struct InitializeVirtualPoolWithToken2022Ctx<'info> {
     )]
     pub pool: AccountLoader<'info, VirtualPool>,
 
-    /// CHECK: Token base vault for the pool
+    /// CHECK: Token base vault for the pool, initialize in contract after the base mint
     #[account(
-        init,
+        mut,
         seeds = [
             TOKEN_VAULT_PREFIX.as_ref(),
             base_mint.key().as_ref(),
             pool.key().as_ref(),
         ],
-        token::mint = base_mint,
-        token::authority = pool_authority,
-        token::token_program = token_program,
-        payer = payer,
         bump,
     )]
-    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_vault: UncheckedAccount<'info>,
 
     /// Token quote vault for the pool
     #[account(
@@ -110,13 +105,55 @@ pub struct InitializeVirtualPoolWithToken2022Ctx<'info> {
     pub token_program: Program<'info, Token2022>,
     // Sysvar for program account
     pub system_program: Program<'info, System>,
+
+    /// global state, instruction is rejected when emergency stop is on
+    #[account(
+        address = const_pda::global_state::ID,
+        constraint = !global_state.load()?.is_emergency_stopped() @ PoolError::EmergencyStopped,
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    /// payer quote token account, only required if pool creation fee is paid in quote token
+    #[account(mut)]
+    pub payer_token_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: fee claimer of config, only required if pool creation fee is paid in SOL
+    #[account(mut)]
+    pub partner: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: protocol treasury, only required if pool creation fee is paid in SOL with protocol cut
+    #[account(mut, address = treasury::ID)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// pool creator authority signer, not required if approval is verified by ed25519 instruction
+    pub pool_creator_authority_signer: Option<Signer<'info>>,
+
+    /// CHECK: instructions sysvar, only required if approval is verified by ed25519 instruction
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// token metadata config of config, only required if config has token metadata config
+    pub token_metadata_config: Option<AccountLoader<'info, TokenMetadataConfig>>,
+
+    /// fee split of config, only required if pool creation fee is paid in SOL and config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
 }
 
 pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, InitializeVirtualPoolWithToken2022Ctx<'info>>,
     params: InitializePoolParameters,
 ) -> Result<()> {
-    let config = ctx.accounts.config.load()?;
+    let mut config = ctx.accounts.config.load_mut()?;
+    process_verify_pool_creation_approval(ProcessVerifyPoolCreationApprovalParams {
+        config_key: ctx.accounts.config.key(),
+        config: &config,
+        creator: ctx.accounts.creator.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        pool_creator_authority_signer: ctx.accounts.pool_creator_authority_signer.as_ref(),
+        instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+    })?;
+    // pool count tells whether config can be fully updated or closed
+    config.increase_pool_count()?;
     let token_type_value =
         TokenType::try_from(config.token_type).map_err(|_| PoolError::InvalidTokenType)?;
     require!(
@@ -124,7 +161,25 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
         PoolError::InvalidTokenType
     );
 
-    let InitializePoolParameters { name, symbol, uri } = params;
+    let InitializePoolParameters {
+        name,
+        symbol,
+        uri,
+        curve_variant,
+    } = params;
+
+    process_initialize_token2022_base_mint(ProcessInitializeToken2022BaseMintParams {
+        config: &config,
+        creator: ctx.accounts.creator.key(),
+        pool: ctx.accounts.pool.key(),
+        base_mint: &ctx.accounts.base_mint,
+        base_vault: &ctx.accounts.base_vault,
+        base_vault_bump: ctx.bumps.base_vault,
+        pool_authority: &ctx.accounts.pool_authority,
+        payer: &ctx.accounts.payer,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+    })?;
 
     // initialize metadata
     let cpi_accounts = TokenMetadataInitialize {
@@ -143,6 +198,31 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
     );
     token_metadata_initialize(cpi_ctx, name, symbol, uri)?;
 
+    // metadata options of config are written as additional metadata while creator is update authority
+    let token_metadata_config = get_token_metadata_config(
+        &ctx.accounts.config.key(),
+        &config,
+        ctx.accounts.token_metadata_config.as_ref(),
+    )?;
+    if let Some(token_metadata_config) = token_metadata_config.as_ref() {
+        for (key, value) in
+            token_metadata_config.get_additional_metadata(ctx.accounts.creator.key())
+        {
+            token_metadata_update_field(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: ctx.accounts.token_program.to_account_info(),
+                        metadata: ctx.accounts.base_mint.to_account_info(),
+                        update_authority: ctx.accounts.creator.to_account_info(),
+                    },
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
+    }
+
     // transfer minimum rent to mint account
     update_account_lamports_to_minimum_balance(
         ctx.accounts.base_mint.to_account_info(),
@@ -152,8 +232,14 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
 
     let token_authority = config.get_token_authority()?;
 
-    let token_update_authority =
-        token_authority.get_update_authority(ctx.accounts.creator.key(), config.fee_claimer.key());
+    // metadata that is not mutable has no update authority
+    let token_update_authority = if token_metadata_config
+        .is_none_or(|token_metadata_config| token_metadata_config.is_mutable())
+    {
+        token_authority.get_update_authority(ctx.accounts.creator.key(), config.fee_claimer.key())
+    } else {
+        None
+    };
 
     anchor_spl::token_interface::set_authority(
         CpiContext::new_with_signer(
@@ -189,8 +275,16 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
         new_update_token_metadata_authority,
     )?;
 
-    let config = ctx.accounts.config.load()?;
+    // creator picks curve of config or one of its curve variants
+    let config = get_config_with_curve_variant(
+        &ctx.accounts.config.key(),
+        &config,
+        curve_variant,
+        ctx.remaining_accounts,
+    )?;
     let initial_base_supply = config.get_initial_base_supply()?;
+    // transfer fee of base token leaving the vault at migration is paid from the buffer
+    let base_transfer_fee_buffer = config.get_base_transfer_fee_buffer()?;
 
     // mint token
     let seeds = pool_authority_seeds!(const_pda::pool_authority::BUMP);
@@ -204,7 +298,7 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
             },
             &[&seeds[..]],
         ),
-        initial_base_supply,
+        initial_base_supply.safe_add(base_transfer_fee_buffer)?,
     )?;
 
     // update mint authority
@@ -241,6 +335,22 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
         activation_point,
         initial_base_supply,
     );
+    pool.curve_variant = curve_variant;
+    pool.non_transferable_flag = config.non_transferable_flag;
+
+    process_collect_pool_creation_fee(ProcessCollectPoolCreationFeeParams {
+        config: &config,
+        pool: &mut pool,
+        payer: &ctx.accounts.payer,
+        quote_mint: &ctx.accounts.quote_mint,
+        quote_vault: &ctx.accounts.quote_vault,
+        token_quote_program: &ctx.accounts.token_quote_program,
+        system_program: &ctx.accounts.system_program,
+        payer_token_quote_account: ctx.accounts.payer_token_quote_account.as_deref(),
+        partner: ctx.accounts.partner.as_ref(),
+        treasury: ctx.accounts.treasury.as_ref(),
+        fee_split: ctx.accounts.fee_split.as_ref(),
+    })?;
 
     emit_cpi!(EvtInitializePool {
         pool: ctx.accounts.pool.key(),
@@ -249,6 +359,9 @@ pub fn handle_initialize_virtual_pool_with_token2022<'c: 'info, 'info>(
         base_mint: ctx.accounts.base_mint.key(),
         pool_type: PoolType::Token2022.into(),
         activation_point,
+        pool_creation_fee: config.pool_fees.pool_creation_fee,
+        pool_creation_fee_token: config.pool_fees.pool_creation_fee_token,
+        curve_variant,
     });
     Ok(())
 }
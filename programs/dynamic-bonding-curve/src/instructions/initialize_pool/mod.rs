@@ -2,5 +2,13 @@ pub mod ix_initialize_virtual_pool_with_spl_token;
 pub use ix_initialize_virtual_pool_with_spl_token::*;
 pub mod ix_initialize_virtual_pool_with_token2022;
 pub use ix_initialize_virtual_pool_with_token2022::*;
+pub mod ix_initialize_virtual_pool_with_existing_mint;
+pub use ix_initialize_virtual_pool_with_existing_mint::*;
 pub mod process_create_token_metadata;
 pub use process_create_token_metadata::*;
+pub mod process_collect_pool_creation_fee;
+pub use process_collect_pool_creation_fee::*;
+pub mod process_verify_pool_creation_approval;
+pub use process_verify_pool_creation_approval::*;
+pub mod process_initialize_token2022_base_mint;
+pub use process_initialize_token2022_base_mint::*;
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{FeeSplit, PoolConfig, PoolCreationFeeSplit, PoolCreationFeeToken, VirtualPool},
+    token::{
+        calculate_transfer_fee_included_amount, get_epoch_transfer_fee, transfer_from_user,
+        TransferFeeIncludedAmount,
+    },
+    PoolError,
+};
+
+pub struct ProcessCollectPoolCreationFeeParams<'a, 'info> {
+    pub config: &'a PoolConfig,
+    pub pool: &'a mut VirtualPool,
+    pub payer: &'a Signer<'info>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub quote_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_quote_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: &'a Program<'info, System>,
+    pub payer_token_quote_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub partner: Option<&'a UncheckedAccount<'info>>,
+    pub treasury: Option<&'a UncheckedAccount<'info>>,
+    pub fee_split: Option<&'a AccountLoader<'info, FeeSplit>>,
+}
+
+/// Collect pool creation fee from payer, SOL is sent to partner (or fee split of config) and treasury directly,
+/// quote token is sent to quote vault and claimed with trading fees
+pub fn process_collect_pool_creation_fee(
+    params: ProcessCollectPoolCreationFeeParams<'_, '_>,
+) -> Result<()> {
+    let ProcessCollectPoolCreationFeeParams {
+        config,
+        pool,
+        payer,
+        quote_mint,
+        quote_vault,
+        token_quote_program,
+        system_program,
+        payer_token_quote_account,
+        partner,
+        treasury,
+        fee_split,
+    } = params;
+
+    let pool_creation_fee = config.pool_fees.pool_creation_fee;
+    if pool_creation_fee == 0 {
+        return Ok(());
+    }
+    let PoolCreationFeeSplit {
+        partner_fee,
+        protocol_fee,
+    } = config.split_pool_creation_fee()?;
+
+    match config.get_pool_creation_fee_token()? {
+        PoolCreationFeeToken::Sol => {
+            // partner share is held by fee split and credited to its recipients
+            let partner = if config.has_fee_split() {
+                let fee_split = fee_split.ok_or(PoolError::InvalidAccount)?;
+                let mut fee_split_state = fee_split.load_mut()?;
+                require!(
+                    fee_split_state.config.eq(&pool.config),
+                    PoolError::InvalidAccount
+                );
+                fee_split_state.distribute(0, partner_fee)?;
+                fee_split.to_account_info()
+            } else {
+                let partner = partner.ok_or(PoolError::InvalidAccount)?;
+                require!(
+                    partner.key().eq(&config.fee_claimer),
+                    PoolError::InvalidAccount
+                );
+                partner.to_account_info()
+            };
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: partner,
+                    },
+                ),
+                partner_fee,
+            )?;
+
+            if protocol_fee > 0 {
+                let treasury = treasury.ok_or(PoolError::InvalidAccount)?;
+                system_program::transfer(
+                    CpiContext::new(
+                        system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: payer.to_account_info(),
+                            to: treasury.to_account_info(),
+                        },
+                    ),
+                    protocol_fee,
+                )?;
+            }
+        }
+        PoolCreationFeeToken::QuoteToken => {
+            let payer_token_quote_account =
+                payer_token_quote_account.ok_or(PoolError::InvalidAccount)?;
+            // payer pays transfer fee of quote mint on top, so quote vault receives the full fee
+            let TransferFeeIncludedAmount {
+                amount: transfer_fee_included_pool_creation_fee,
+                ..
+            } = calculate_transfer_fee_included_amount(
+                get_epoch_transfer_fee(&quote_mint.to_account_info())?.as_ref(),
+                pool_creation_fee,
+            )?;
+            transfer_from_user(
+                payer,
+                quote_mint,
+                payer_token_quote_account,
+                quote_vault,
+                token_quote_program,
+                transfer_fee_included_pool_creation_fee,
+            )?;
+            pool.accumulate_pool_creation_fee(partner_fee, protocol_fee)?;
+        }
+    }
+    Ok(())
+}
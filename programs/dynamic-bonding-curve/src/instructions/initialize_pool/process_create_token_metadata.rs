@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use mpl_token_metadata::types::DataV2;
+use mpl_token_metadata::types::{Collection, Creator, DataV2};
 
-use crate::state::TokenAuthorityOption;
+use crate::state::{TokenAuthorityOption, TokenMetadataConfig};
 pub struct ProcessCreateTokenMetadataParams<'a, 'info> {
     pub system_program: AccountInfo<'info>,
     pub payer: AccountInfo<'info>,
@@ -16,6 +16,7 @@ pub struct ProcessCreateTokenMetadataParams<'a, 'info> {
     pub pool_authority_bump: u8,
     pub token_authority: TokenAuthorityOption,
     pub partner: Pubkey,
+    pub token_metadata_config: Option<&'a TokenMetadataConfig>,
 }
 
 pub fn process_create_token_metadata(params: ProcessCreateTokenMetadataParams) -> Result<()> {
@@ -26,7 +27,10 @@ pub fn process_create_token_metadata(params: ProcessCreateTokenMetadataParams) -
         &params.metadata_program,
     );
 
-    let is_mutable = params.token_authority != TokenAuthorityOption::Immutable;
+    let is_mutable = params.token_authority != TokenAuthorityOption::Immutable
+        && params
+            .token_metadata_config
+            .is_none_or(|token_metadata_config| token_metadata_config.is_mutable());
 
     builder.mint(&params.mint);
     builder.update_authority(&params.pool_authority, false);
@@ -35,7 +39,7 @@ pub fn process_create_token_metadata(params: ProcessCreateTokenMetadataParams) -
     builder.is_mutable(is_mutable);
     builder.payer(&params.payer);
     builder.system_program(&params.system_program);
-    let data = DataV2 {
+    let mut data = DataV2 {
         collection: None,
         creators: None,
         name: params.name.to_string(),
@@ -44,6 +48,27 @@ pub fn process_create_token_metadata(params: ProcessCreateTokenMetadataParams) -
         uses: None,
         uri: params.uri.to_string(),
     };
+    // creators and collection are unverified, they verify themselves with metadata program
+    if let Some(token_metadata_config) = params.token_metadata_config {
+        let creators = token_metadata_config.get_creators(params.creator.key());
+        data.seller_fee_basis_points = token_metadata_config.seller_fee_basis_points;
+        data.creators = (!creators.is_empty()).then(|| {
+            creators
+                .into_iter()
+                .map(|(address, share)| Creator {
+                    address,
+                    verified: false,
+                    share,
+                })
+                .collect()
+        });
+        data.collection = token_metadata_config
+            .get_collection()
+            .map(|key| Collection {
+                verified: false,
+                key,
+            });
+    }
     builder.data(data);
 
     builder.invoke_signed(&[&seeds[..]])?;
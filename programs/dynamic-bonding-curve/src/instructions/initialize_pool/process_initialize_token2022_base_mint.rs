@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::{
+    token_2022::{
+        initialize_account3, initialize_mint2,
+        spl_token_2022::{
+            extension::ExtensionType,
+            state::{Account, Mint},
+        },
+        InitializeAccount3, InitializeMint2, Token2022,
+    },
+    token_interface::{
+        metadata_pointer_initialize, transfer_fee_initialize, MetadataPointerInitialize,
+        TransferFeeInitialize,
+    },
+};
+
+use crate::{constants::seeds::TOKEN_VAULT_PREFIX, state::PoolConfig};
+
+pub struct ProcessInitializeToken2022BaseMintParams<'a, 'info> {
+    pub config: &'a PoolConfig,
+    pub creator: Pubkey,
+    pub pool: Pubkey,
+    pub base_mint: &'a Signer<'info>,
+    pub base_vault: &'a UncheckedAccount<'info>,
+    pub base_vault_bump: u8,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub payer: &'a Signer<'info>,
+    pub token_program: &'a Program<'info, Token2022>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Create token 2022 base mint with extensions of config and the base vault of the pool,
+/// mint extensions must be initialized before the mint, so they can't be created by anchor constraints
+pub fn process_initialize_token2022_base_mint(
+    params: ProcessInitializeToken2022BaseMintParams<'_, '_>,
+) -> Result<()> {
+    let ProcessInitializeToken2022BaseMintParams {
+        config,
+        creator,
+        pool,
+        base_mint,
+        base_vault,
+        base_vault_bump,
+        pool_authority,
+        payer,
+        token_program,
+        system_program,
+    } = params;
+
+    // transfer hook is not supported, damm v2 doesn't forward extra accounts of the hook when it transfers base token at migration
+    let mut mint_extensions = vec![ExtensionType::MetadataPointer];
+    if config.has_base_transfer_fee() {
+        mint_extensions.push(ExtensionType::TransferFeeConfig);
+    }
+
+    // create mint
+    let mint_space = ExtensionType::try_calculate_account_len::<Mint>(&mint_extensions)?;
+    create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: base_mint.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(mint_space),
+        mint_space as u64,
+        token_program.key,
+    )?;
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: base_mint.to_account_info(),
+            },
+        ),
+        Some(pool_authority.key()),
+        Some(base_mint.key()),
+    )?;
+
+    if config.has_base_transfer_fee() {
+        // transfer fee can't be updated, so swap and migration math of the pool stay correct
+        let withdraw_withheld_authority = config
+            .get_base_transfer_fee_authority()?
+            .get_withdraw_withheld_authority(creator, config.fee_claimer);
+        transfer_fee_initialize(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferFeeInitialize {
+                    token_program_id: token_program.to_account_info(),
+                    mint: base_mint.to_account_info(),
+                },
+            ),
+            None,
+            Some(&withdraw_withheld_authority),
+            config.pool_fees.base_transfer_fee_bps,
+            config.pool_fees.base_max_transfer_fee,
+        )?;
+    }
+
+    // pool authority freezes base token accounts outside of swap until migration
+    let freeze_authority = config.is_non_transferable().then(|| pool_authority.key());
+    initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: base_mint.to_account_info(),
+            },
+        ),
+        config.token_decimal,
+        &pool_authority.key(),
+        freeze_authority.as_ref(),
+    )?;
+
+    // create base vault
+    let vault_extensions = ExtensionType::get_required_init_account_extensions(&mint_extensions);
+    let vault_space = ExtensionType::try_calculate_account_len::<Account>(&vault_extensions)?;
+    let base_mint_key = base_mint.key();
+    let vault_seeds = &[
+        TOKEN_VAULT_PREFIX,
+        base_mint_key.as_ref(),
+        pool.as_ref(),
+        &[base_vault_bump],
+    ];
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: base_vault.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        Rent::get()?.minimum_balance(vault_space),
+        vault_space as u64,
+        token_program.key,
+    )?;
+
+    initialize_account3(CpiContext::new(
+        token_program.to_account_info(),
+        InitializeAccount3 {
+            account: base_vault.to_account_info(),
+            mint: base_mint.to_account_info(),
+            authority: pool_authority.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::{ed25519::is_ed25519_signed_message, state::PoolConfig, PoolError};
+
+/// domain separator of pool creation approval message, so signatures over it can't be reused as messages of other purposes
+pub const POOL_CREATION_APPROVAL_DOMAIN: &[u8] = b"dbc-pool-creation-approval";
+
+/// message that pool creator authority signs with ed25519 to approve a creator to launch base mint on a config
+pub fn get_pool_creation_approval_message(
+    config: &Pubkey,
+    creator: &Pubkey,
+    base_mint: &Pubkey,
+) -> Vec<u8> {
+    [
+        POOL_CREATION_APPROVAL_DOMAIN,
+        crate::ID.as_ref(),
+        config.as_ref(),
+        creator.as_ref(),
+        base_mint.as_ref(),
+    ]
+    .concat()
+}
+
+pub struct ProcessVerifyPoolCreationApprovalParams<'a, 'info> {
+    pub config_key: Pubkey,
+    pub config: &'a PoolConfig,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub pool_creator_authority_signer: Option<&'a Signer<'info>>,
+    pub instructions_sysvar: Option<&'a UncheckedAccount<'info>>,
+}
+
+/// If config has pool creator authority, the authority must sign the transaction,
+/// or an ed25519 instruction in the transaction must verify its approval message for the creator and base mint
+pub fn process_verify_pool_creation_approval(
+    params: ProcessVerifyPoolCreationApprovalParams<'_, '_>,
+) -> Result<()> {
+    let ProcessVerifyPoolCreationApprovalParams {
+        config_key,
+        config,
+        creator,
+        base_mint,
+        pool_creator_authority_signer,
+        instructions_sysvar,
+    } = params;
+
+    if !config.has_pool_creator_authority() {
+        return Ok(());
+    }
+
+    let authority = config.pool_creator_authority;
+
+    if pool_creator_authority_signer.is_some_and(|signer| signer.key().eq(&authority)) {
+        return Ok(());
+    }
+
+    let instructions_sysvar = instructions_sysvar.ok_or(PoolError::PoolCreationNotApproved)?;
+    require!(
+        instructions_sysvar.key().eq(&sysvar::instructions::ID),
+        PoolError::InvalidAccount
+    );
+    let instructions_sysvar = instructions_sysvar.to_account_info();
+    let message = get_pool_creation_approval_message(&config_key, &creator, &base_mint);
+    let current_index = sysvar::instructions::load_current_index_checked(&instructions_sysvar)?;
+    for i in 0..current_index {
+        let instruction =
+            sysvar::instructions::load_instruction_at_checked(i.into(), &instructions_sysvar)?;
+        if is_ed25519_signed_message(&instruction, &authority, &message) {
+            return Ok(());
+        }
+    }
+
+    Err(PoolError::PoolCreationNotApproved.into())
+}
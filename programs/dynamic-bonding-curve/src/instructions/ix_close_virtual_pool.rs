@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    state::{MigrationOption, PoolConfig, VirtualPool},
+    token::{close_pool_token_account, harvest_withheld_transfer_fee},
+    EvtCloseVirtualPool, MeteoraDammMigrationMetadata, PoolError,
+};
+
+/// Accounts for closing a migrated pool and its vaults
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseVirtualPoolCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(
+        mut,
+        close = creator,
+        has_one = config,
+        has_one = creator,
+        has_one = base_vault,
+        has_one = quote_vault,
+    )]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
+    /// The vault token account for base token, must be empty
+    #[account(mut, token::token_program = token_base_program)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for quote token, must be empty
+    #[account(mut, token::token_program = token_quote_program)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// damm v1 migration metadata, only required if pool is migrated to damm v1, lp must be locked and claimed before closing
+    #[account(has_one = virtual_pool)]
+    pub migration_metadata: Option<AccountLoader<'info, MeteoraDammMigrationMetadata>>,
+
+    /// CHECK: base mint, only required if transfer fee is withheld in base vault, it is harvested to the mint before closing
+    #[account(mut, address = virtual_pool.load()?.base_mint)]
+    pub base_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote mint, only required if transfer fee is withheld in quote vault, it is harvested to the mint before closing
+    #[account(mut, address = config.load()?.quote_mint)]
+    pub quote_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: pool creator, receives rent of pool and vaults
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Token base program
+    pub token_base_program: Interface<'info, TokenInterface>,
+
+    /// Token quote program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_close_virtual_pool(ctx: Context<CloseVirtualPoolCtx>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let pool = ctx.accounts.virtual_pool.load()?;
+    require!(
+        pool.is_fully_withdrawn(&config)?,
+        PoolError::NotPermitToDoThisAction
+    );
+    // holders of non-transferable pool thaw base token accounts through the pool
+    require!(
+        !pool.is_non_transferable() || !pool.has_frozen_account(),
+        PoolError::NotPermitToDoThisAction
+    );
+    // damm v1 lp lock and claim require the pool
+    let migration_option = MigrationOption::try_from(config.migration_option)
+        .map_err(|_| PoolError::InvalidMigrationOption)?;
+    if migration_option == MigrationOption::MeteoraDamm {
+        let migration_metadata = ctx
+            .accounts
+            .migration_metadata
+            .as_ref()
+            .ok_or(PoolError::NotPermitToDoThisAction)?
+            .load()?;
+        require!(
+            migration_metadata.is_lp_distributed(),
+            PoolError::NotPermitToDoThisAction
+        );
+    }
+
+    // withheld transfer fees must be harvested before closing vaults
+    harvest_withheld_transfer_fee(
+        &ctx.accounts.base_vault,
+        ctx.accounts.base_mint.as_ref(),
+        &ctx.accounts.token_base_program,
+    )?;
+    harvest_withheld_transfer_fee(
+        &ctx.accounts.quote_vault,
+        ctx.accounts.quote_mint.as_ref(),
+        &ctx.accounts.token_quote_program,
+    )?;
+
+    // token program rejects closing vaults that are not empty
+    close_pool_token_account(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.base_vault,
+        ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.token_base_program,
+        const_pda::pool_authority::BUMP,
+    )?;
+    close_pool_token_account(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.quote_vault,
+        ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.token_quote_program,
+        const_pda::pool_authority::BUMP,
+    )?;
+
+    config.decrease_pool_count()?;
+
+    emit_cpi!(EvtCloseVirtualPool {
+        pool: ctx.accounts.virtual_pool.key(),
+        config: ctx.accounts.config.key(),
+        rent_receiver: ctx.accounts.creator.key(),
+    });
+    Ok(())
+}
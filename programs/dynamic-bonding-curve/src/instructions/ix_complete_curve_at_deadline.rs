@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    activation_handler::get_current_point,
+    state::{get_pool_config, MigrationProgress, PoolConfig, VirtualPool},
+    EvtCurveComplete, PoolError,
+};
+
+/// Accounts for completing curve at the migration deadline
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompleteCurveAtDeadlineCtx<'info> {
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(mut, has_one = base_vault, has_one = config)]
+    pub pool: AccountLoader<'info, VirtualPool>,
+
+    /// The vault token account for base token
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handle_complete_curve_at_deadline<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CompleteCurveAtDeadlineCtx<'info>>,
+) -> Result<()> {
+    let config = ctx.accounts.config.load()?;
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
+
+    require!(
+        pool.get_migration_progress()? == MigrationProgress::PreBondingCurve,
+        PoolError::PoolIsCompleted
+    );
+
+    let current_point = get_current_point(config.activation_type)?;
+    require!(
+        pool.is_migration_deadline_reached(&config, current_point)?,
+        PoolError::MigrationDeadlineIsNotReached
+    );
+    require!(
+        pool.can_complete_curve_by_deadline(&config, current_point)?,
+        PoolError::InvalidQuoteThreshold
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp as u64;
+    pool.complete_curve(&config, ctx.accounts.base_vault.amount, current_timestamp)?;
+
+    emit_cpi!(EvtCurveComplete {
+        pool: ctx.accounts.pool.key(),
+        config: ctx.accounts.config.key(),
+        base_reserve: pool.base_reserve,
+        quote_reserve: pool.quote_reserve,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda, route_creator_fee_to_creator_fee_split, route_partner_quote_to_fee_split,
+    state::{get_pool_config, CreatorFeeSplit, FeeSplit, PoolConfig, VirtualPool},
+    token::transfer_from_pool,
+    treasury, EvtDistributeSurplus, PoolError,
+};
+
+/// Accounts for distributing surplus to partner, creator and protocol
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeSurplusCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(
+        mut,
+        has_one = quote_vault,
+        has_one = config,
+    )]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
+    /// The partner token account, owned by fee claimer or fee split vault if config has fee split
+    #[account(mut)]
+    pub partner_token_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The creator token account, owned by creator or creator fee split vault if pool has creator fee split
+    #[account(mut)]
+    pub creator_token_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The treasury quote token account
+    #[account(
+        mut,
+        associated_token::authority = treasury::ID,
+        associated_token::mint = quote_mint,
+        associated_token::token_program = token_quote_program,
+    )]
+    pub protocol_token_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for quote token
+    #[account(mut, token::token_program = token_quote_program, token::mint = quote_mint)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of quote token
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// fee split of config, only required if config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
+
+    /// creator fee split of pool, only required if pool has creator fee split
+    #[account(mut)]
+    pub creator_fee_split: Option<AccountLoader<'info, CreatorFeeSplit>>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+}
+
+/// Pay surplus of all parties that have not withdrawn yet
+pub fn handle_distribute_surplus<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DistributeSurplusCtx<'info>>,
+) -> Result<()> {
+    let config = ctx.accounts.config.load()?;
+    let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
+
+    let migration_quote_threshold = pool.get_migration_quote_threshold(&config);
+    // Make sure pool has been completed
+    require!(
+        pool.is_curve_complete(migration_quote_threshold),
+        PoolError::NotPermitToDoThisAction
+    );
+    require!(
+        pool.is_partner_withdraw_surplus == 0
+            || pool.is_creator_withdraw_surplus == 0
+            || pool.is_protocol_withdraw_surplus == 0,
+        PoolError::SurplusHasBeenWithdraw
+    );
+
+    let total_surplus = pool.get_total_surplus(migration_quote_threshold)?;
+
+    let partner_surplus_amount = if pool.is_partner_withdraw_surplus == 0 {
+        let partner_surplus_amount = pool.get_partner_surplus(&config, total_surplus)?;
+        if config.has_fee_split() {
+            route_partner_quote_to_fee_split(
+                &ctx.accounts.config.key(),
+                &config,
+                &ctx.accounts.fee_split,
+                &ctx.accounts.quote_mint,
+                &ctx.accounts.partner_token_quote_account,
+                partner_surplus_amount,
+            )?;
+        } else {
+            require!(
+                ctx.accounts
+                    .partner_token_quote_account
+                    .owner
+                    .eq(&config.fee_claimer),
+                PoolError::InvalidAccount
+            );
+        }
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.quote_mint,
+            &ctx.accounts.quote_vault,
+            &ctx.accounts.partner_token_quote_account,
+            &ctx.accounts.token_quote_program,
+            partner_surplus_amount,
+            const_pda::pool_authority::BUMP,
+        )?;
+        pool.update_partner_withdraw_surplus();
+        partner_surplus_amount
+    } else {
+        0
+    };
+
+    let creator_surplus_amount = if pool.is_creator_withdraw_surplus == 0 {
+        let creator_surplus_amount = pool.get_creator_surplus(&config, total_surplus)?;
+        if pool.has_creator_fee_split() {
+            route_creator_fee_to_creator_fee_split(
+                &ctx.accounts.virtual_pool.key(),
+                &pool,
+                &ctx.accounts.creator_fee_split,
+                None,
+                (
+                    &ctx.accounts.quote_mint,
+                    &ctx.accounts.creator_token_quote_account,
+                ),
+                0,
+                creator_surplus_amount,
+            )?;
+        } else {
+            require!(
+                ctx.accounts
+                    .creator_token_quote_account
+                    .owner
+                    .eq(&pool.creator),
+                PoolError::InvalidAccount
+            );
+        }
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.quote_mint,
+            &ctx.accounts.quote_vault,
+            &ctx.accounts.creator_token_quote_account,
+            &ctx.accounts.token_quote_program,
+            creator_surplus_amount,
+            const_pda::pool_authority::BUMP,
+        )?;
+        pool.update_creator_withdraw_surplus();
+        creator_surplus_amount
+    } else {
+        0
+    };
+
+    let protocol_surplus_amount = if pool.is_protocol_withdraw_surplus == 0 {
+        let protocol_surplus_amount =
+            pool.get_protocol_surplus(&config, migration_quote_threshold)?;
+        transfer_from_pool(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.quote_mint,
+            &ctx.accounts.quote_vault,
+            &ctx.accounts.protocol_token_quote_account,
+            &ctx.accounts.token_quote_program,
+            protocol_surplus_amount,
+            const_pda::pool_authority::BUMP,
+        )?;
+        pool.update_protocol_withdraw_surplus();
+        protocol_surplus_amount
+    } else {
+        0
+    };
+
+    emit_cpi!(EvtDistributeSurplus {
+        pool: ctx.accounts.virtual_pool.key(),
+        partner_surplus_amount,
+        creator_surplus_amount,
+        protocol_surplus_amount,
+    });
+    Ok(())
+}
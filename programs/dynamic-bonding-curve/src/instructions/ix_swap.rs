@@ -1,13 +1,15 @@
 use crate::math::safe_math::SafeMath;
-use crate::state::MigrationProgress;
 use crate::EvtCurveComplete;
 use crate::{
     activation_handler::get_current_point,
     const_pda,
     params::swap::TradeDirection,
     state::fee::FeeMode,
-    state::{PoolConfig, VirtualPool},
-    token::{transfer_from_pool, transfer_from_user},
+    state::{get_pool_config, GlobalState, PoolConfig, VirtualPool},
+    token::{
+        calculate_transfer_fee_excluded_amount, freeze_token_account, get_epoch_transfer_fee,
+        thaw_token_account, transfer_from_pool, transfer_from_user, TransferFeeExcludedAmount,
+    },
     EvtSwap, PoolError,
 };
 use anchor_lang::prelude::*;
@@ -75,6 +77,13 @@ pub struct SwapCtx<'info> {
     /// referral token account
     #[account(mut)]
     pub referral_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// global state, instruction is rejected when emergency stop is on
+    #[account(
+        address = const_pda::global_state::ID,
+        constraint = !global_state.load()?.is_emergency_stopped() @ PoolError::EmergencyStopped,
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
 }
 
 impl<'info> SwapCtx<'info> {
@@ -88,7 +97,10 @@ impl<'info> SwapCtx<'info> {
 }
 
 // TODO impl swap exact out
-pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()> {
+pub fn handle_swap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SwapCtx<'info>>,
+    params: SwapParameters,
+) -> Result<()> {
     let SwapParameters {
         amount_in,
         minimum_amount_out,
@@ -127,9 +139,13 @@ pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()>
 
     let config = ctx.accounts.config.load()?;
     let mut pool = ctx.accounts.pool.load_mut()?;
+    // curve variants of config is the last remaining account, after instructions sysvar of rate limiter
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
 
     let current_point = get_current_point(config.activation_type)?;
 
+    require!(!pool.is_paused(), PoolError::PoolIsPaused);
+
     // another validation to prevent snipers to craft multiple swap instructions in 1 tx
     // (if we dont do this, they are able to concat 16 swap instructions in 1 tx)
     if let Ok(rate_limiter) = config.pool_fees.base_fee.get_fee_rate_limiter() {
@@ -144,24 +160,66 @@ pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()>
 
     // validate if it is over threshold
     require!(
-        !pool.is_curve_complete(config.migration_quote_threshold),
+        !pool.is_curve_complete(pool.get_migration_quote_threshold(&config)),
         PoolError::PoolIsCompleted
     );
 
+    // pool raised enough at the deadline, it must be completed instead of swapping
+    require!(
+        !pool.can_complete_curve_by_deadline(&config, current_point)?,
+        PoolError::PoolIsCompleted
+    );
+
+    // pool missed the deadline, holders can only sell base token back to the curve to get quote token back
+    if pool.is_migration_deadline_missed(&config, current_point)? {
+        require!(
+            trade_direction == TradeDirection::BaseToQuote,
+            PoolError::MigrationDeadlineMissed
+        );
+    }
+
     // update for dynamic fee reference
     let current_timestamp = Clock::get()?.unix_timestamp as u64;
     pool.update_pre_swap(&config, current_timestamp)?;
 
     let fee_mode = &FeeMode::get_fee_mode(config.collect_fee_mode, trade_direction, has_referral)?;
 
-    let swap_result =
-        pool.get_swap_result(&config, amount_in, fee_mode, trade_direction, current_point)?;
+    // transfer fee of input token is withheld in the vault, so only the rest is swapped
+    let TransferFeeExcludedAmount {
+        amount: transfer_fee_excluded_amount_in,
+        ..
+    } = calculate_transfer_fee_excluded_amount(
+        get_epoch_transfer_fee(&token_in_mint.to_account_info())?.as_ref(),
+        amount_in,
+    )?;
+    require!(transfer_fee_excluded_amount_in > 0, PoolError::AmountIsZero);
 
+    let swap_result = pool.get_swap_result(
+        &config,
+        transfer_fee_excluded_amount_in,
+        fee_mode,
+        trade_direction,
+        current_point,
+    )?;
+
+    // slippage is checked against the amount user receives after transfer fee of output token
+    let TransferFeeExcludedAmount {
+        amount: transfer_fee_excluded_amount_out,
+        ..
+    } = calculate_transfer_fee_excluded_amount(
+        get_epoch_transfer_fee(&token_out_mint.to_account_info())?.as_ref(),
+        swap_result.output_amount,
+    )?;
     require!(
-        swap_result.output_amount >= minimum_amount_out,
+        transfer_fee_excluded_amount_out >= minimum_amount_out,
         PoolError::ExceededSlippage
     );
 
+    require!(
+        !pool.is_circuit_breaker_triggered(&config, swap_result.next_sqrt_price)?,
+        PoolError::CircuitBreakerTriggered
+    );
+
     pool.apply_swap_result(
         &config,
         &swap_result,
@@ -170,6 +228,24 @@ pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()>
         current_timestamp,
     )?;
 
+    // base token account of user stays frozen outside of swap until migration, so base token can't be transferred off the curve
+    let user_base_token_account = match trade_direction {
+        TradeDirection::BaseToQuote => &ctx.accounts.input_token_account,
+        TradeDirection::QuoteToBase => &ctx.accounts.output_token_account,
+    };
+    let is_non_transferable = pool.is_non_transferable();
+    if is_non_transferable {
+        // token account of new holder is frozen for the first time after the swap
+        pool.track_frozen_account(user_base_token_account.is_frozen())?;
+        thaw_token_account(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.base_mint,
+            user_base_token_account,
+            &ctx.accounts.token_base_program,
+            const_pda::pool_authority::BUMP,
+        )?;
+    }
+
     // send to reserve
     transfer_from_user(
         &ctx.accounts.payer,
@@ -191,6 +267,16 @@ pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()>
         const_pda::pool_authority::BUMP,
     )?;
 
+    if is_non_transferable {
+        freeze_token_account(
+            ctx.accounts.pool_authority.to_account_info(),
+            &ctx.accounts.base_mint,
+            user_base_token_account,
+            &ctx.accounts.token_base_program,
+            const_pda::pool_authority::BUMP,
+        )?;
+    }
+
     // send to referral
     if let Some(referral_token_account) = ctx.accounts.referral_token_account.as_ref() {
         if fee_mode.fees_on_base_token {
@@ -227,35 +313,11 @@ pub fn handle_swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()>
         current_timestamp,
     });
 
-    if pool.is_curve_complete(config.migration_quote_threshold) {
+    if pool.is_curve_complete(config.migration_quote_threshold)
+        || pool.can_complete_curve_by_deadline(&config, current_point)?
+    {
         ctx.accounts.base_vault.reload()?;
-        // validate if base reserve is enough token for migration
-        let base_vault_balance = ctx.accounts.base_vault.amount;
-
-        let required_base_balance = config
-            .migration_base_threshold
-            .safe_add(pool.get_protocol_and_trading_base_fee()?)?
-            .safe_add(
-                config
-                    .locked_vesting_config
-                    .to_locked_vesting_params()
-                    .get_total_amount()?,
-            )?;
-
-        require!(
-            base_vault_balance >= required_base_balance,
-            PoolError::InsufficientLiquidityForMigration
-        );
-
-        // set finish time and migration progress
-        pool.finish_curve_timestamp = current_timestamp;
-
-        let locked_vesting_params = config.locked_vesting_config.to_locked_vesting_params();
-        if locked_vesting_params.has_vesting() {
-            pool.set_migration_progress(MigrationProgress::PostBondingCurve.into());
-        } else {
-            pool.set_migration_progress(MigrationProgress::LockedVesting.into());
-        }
+        pool.complete_curve(&config, ctx.accounts.base_vault.amount, current_timestamp)?;
 
         emit_cpi!(EvtCurveComplete {
             pool: ctx.accounts.pool.key(),
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    state::VirtualPool,
+    token::{revoke_freeze_authority, thaw_token_account},
+    PoolError,
+};
+
+/// Accounts for thawing base token account of a non-transferable pool after migration
+#[derive(Accounts)]
+pub struct ThawBaseTokenAccountCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = base_mint)]
+    pub pool: AccountLoader<'info, VirtualPool>,
+
+    /// The mint of base token
+    #[account(mut, mint::token_program = token_base_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Base token account of any holder
+    #[account(mut, token::mint = base_mint, token::token_program = token_base_program)]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token base program
+    pub token_base_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_thaw_base_token_account(ctx: Context<ThawBaseTokenAccountCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(
+        pool.is_non_transferable() && pool.is_migrated == 1,
+        PoolError::NotPermitToDoThisAction
+    );
+    pool.untrack_frozen_account(ctx.accounts.token_account.is_frozen())?;
+
+    thaw_token_account(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.base_mint,
+        &ctx.accounts.token_account,
+        &ctx.accounts.token_base_program,
+        const_pda::pool_authority::BUMP,
+    )?;
+
+    // last frozen holder is thawed, no one can freeze base token accounts anymore
+    if !pool.has_frozen_account() && ctx.accounts.base_mint.freeze_authority.is_some() {
+        revoke_freeze_authority(
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.base_mint.to_account_info(),
+            ctx.accounts.token_base_program.to_account_info(),
+            const_pda::pool_authority::BUMP,
+        )?;
+    }
+
+    Ok(())
+}
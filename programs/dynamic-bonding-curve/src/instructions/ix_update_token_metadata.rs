@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{
+        spl_token_2022::{
+            extension::{BaseStateWithExtensions, StateWithExtensions},
+            state::Mint,
+        },
+        Token2022,
+    },
+    token_interface::{token_metadata_update_field, TokenMetadataUpdateField},
+};
+use mpl_token_metadata::{accounts::Metadata, types::DataV2};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+use crate::{
+    state::{
+        PoolConfig, PoolType, VirtualPool, COLLECTION_KEY, CREATORS_KEY,
+        SELLER_FEE_BASIS_POINTS_KEY,
+    },
+    token::update_account_lamports_to_minimum_balance,
+    EvtUpdateTokenMetadata, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct TokenMetadataFieldParameters {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Default)]
+pub struct UpdateTokenMetadataParameters {
+    /// none means unchanged
+    pub name: Option<String>,
+    /// none means unchanged
+    pub symbol: Option<String>,
+    /// none means unchanged
+    pub uri: Option<String>,
+    /// additional metadata fields to set, token 2022 only
+    pub additional_metadata: Vec<TokenMetadataFieldParameters>,
+}
+
+impl UpdateTokenMetadataParameters {
+    pub fn validate(&self, pool_type: PoolType, has_token_metadata_config: bool) -> Result<()> {
+        if pool_type == PoolType::SplToken {
+            require!(
+                self.additional_metadata.is_empty(),
+                PoolError::InvalidTokenMetadataUpdate
+            );
+        }
+        // fields written from token metadata config are owned by partner
+        if has_token_metadata_config {
+            require!(
+                self.additional_metadata.iter().all(|field| ![
+                    SELLER_FEE_BASIS_POINTS_KEY,
+                    CREATORS_KEY,
+                    COLLECTION_KEY
+                ]
+                .contains(&field.key.as_str())),
+                PoolError::InvalidTokenMetadataUpdate
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Accounts for update authority of base token metadata to update it through the program
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTokenMetadataCtx<'info> {
+    #[account(has_one = config, has_one = base_mint)]
+    pub virtual_pool: AccountLoader<'info, VirtualPool>,
+
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    /// CHECK: base mint, token 2022 metadata is stored in the mint
+    #[account(mut)]
+    pub base_mint: UncheckedAccount<'info>,
+
+    /// update authority stored in the metadata of base mint
+    pub signer: Signer<'info>,
+
+    /// CHECK: metaplex metadata of base mint, only required for spl token
+    #[account(mut)]
+    pub mint_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metadata program, only required for spl token
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// Token 2022 program, only required for token 2022
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    /// pays rent of the grown mint account, only required for token 2022
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_update_token_metadata(
+    ctx: Context<UpdateTokenMetadataCtx>,
+    params: UpdateTokenMetadataParameters,
+) -> Result<()> {
+    let pool = ctx.accounts.virtual_pool.load()?;
+    let config = ctx.accounts.config.load()?;
+
+    let pool_type = PoolType::try_from(pool.pool_type).map_err(|_| PoolError::InvalidTokenType)?;
+    params.validate(pool_type, config.has_token_metadata_config())?;
+
+    match pool_type {
+        PoolType::SplToken => {
+            let mint_metadata = ctx
+                .accounts
+                .mint_metadata
+                .as_ref()
+                .ok_or(PoolError::InvalidAccount)?;
+            let metadata_program = ctx
+                .accounts
+                .metadata_program
+                .as_ref()
+                .ok_or(PoolError::InvalidAccount)?;
+
+            require!(
+                mint_metadata.owner == &mpl_token_metadata::ID,
+                PoolError::InvalidAccount
+            );
+            let metadata = Metadata::try_from(&mint_metadata.to_account_info())
+                .map_err(|_| PoolError::InvalidAccount)?;
+            require!(
+                metadata.mint == ctx.accounts.base_mint.key(),
+                PoolError::InvalidAccount
+            );
+            require!(metadata.is_mutable, PoolError::ImmutableTokenMetadata);
+            require!(
+                ctx.accounts.signer.key() == metadata.update_authority,
+                PoolError::NotPermitToDoThisAction
+            );
+
+            // metaplex pads strings with null bytes, keep royalties, creators and collection
+            let data = DataV2 {
+                name: params
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| metadata.name.trim_end_matches('\0').to_string()),
+                symbol: params
+                    .symbol
+                    .clone()
+                    .unwrap_or_else(|| metadata.symbol.trim_end_matches('\0').to_string()),
+                uri: params
+                    .uri
+                    .clone()
+                    .unwrap_or_else(|| metadata.uri.trim_end_matches('\0').to_string()),
+                seller_fee_basis_points: metadata.seller_fee_basis_points,
+                creators: metadata.creators,
+                collection: metadata.collection,
+                uses: metadata.uses,
+            };
+
+            mpl_token_metadata::instructions::UpdateMetadataAccountV2CpiBuilder::new(
+                metadata_program,
+            )
+            .metadata(mint_metadata)
+            .update_authority(&ctx.accounts.signer)
+            .data(data)
+            .invoke()?;
+        }
+        PoolType::Token2022 => {
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(PoolError::InvalidAccount)?;
+            let payer = ctx
+                .accounts
+                .payer
+                .as_ref()
+                .ok_or(PoolError::InvalidAccount)?;
+
+            let update_authority = get_token_2022_metadata_update_authority(
+                &ctx.accounts.base_mint.try_borrow_data()?,
+            )?
+            .ok_or(PoolError::ImmutableTokenMetadata)?;
+            require!(
+                ctx.accounts.signer.key() == update_authority,
+                PoolError::NotPermitToDoThisAction
+            );
+
+            let fields = [
+                (Field::Name, params.name.clone()),
+                (Field::Symbol, params.symbol.clone()),
+                (Field::Uri, params.uri.clone()),
+            ]
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+            .chain(
+                params
+                    .additional_metadata
+                    .iter()
+                    .map(|field| (Field::Key(field.key.clone()), field.value.clone())),
+            );
+            for (field, value) in fields {
+                token_metadata_update_field(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TokenMetadataUpdateField {
+                            program_id: token_program.to_account_info(),
+                            metadata: ctx.accounts.base_mint.to_account_info(),
+                            update_authority: ctx.accounts.signer.to_account_info(),
+                        },
+                    ),
+                    field,
+                    value,
+                )?;
+            }
+
+            // mint account is reallocated when metadata grows
+            update_account_lamports_to_minimum_balance(
+                ctx.accounts.base_mint.to_account_info(),
+                payer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+    }
+
+    emit_cpi!(EvtUpdateTokenMetadata {
+        pool: ctx.accounts.virtual_pool.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        update_authority: ctx.accounts.signer.key(),
+        params,
+    });
+
+    Ok(())
+}
+
+/// update authority stored in token 2022 metadata of the mint, none means metadata is immutable
+pub fn get_token_2022_metadata_update_authority(mint_data: &[u8]) -> Result<Option<Pubkey>> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    let token_metadata = mint
+        .get_variable_len_extension::<TokenMetadata>()
+        .map_err(|_| PoolError::InvalidAccount)?;
+    Ok(Option::<Pubkey>::from(token_metadata.update_authority))
+}
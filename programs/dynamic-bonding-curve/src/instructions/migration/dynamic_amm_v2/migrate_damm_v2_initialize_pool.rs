@@ -18,8 +18,12 @@ use crate::{
     params::fee_parameters::{to_bps, to_numerator},
     safe_math::SafeMath,
     state::{
-        LiquidityDistribution, MigrationAmount, MigrationFeeOption, MigrationOption,
-        MigrationProgress, PoolConfig, VirtualPool,
+        get_pool_config, LiquidityDistribution, MigrationAmount, MigrationFeeOption,
+        MigrationOption, MigrationProgress, PoolConfig, VirtualPool,
+    },
+    token::{
+        calculate_transfer_fee_excluded_amount, get_epoch_transfer_fee, revoke_freeze_authority,
+        TransferFeeExcludedAmount,
     },
     *,
 };
@@ -474,8 +478,10 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
     let migration_fee_option = MigrationFeeOption::try_from(config.migration_fee_option)
         .map_err(|_| PoolError::InvalidMigrationFeeOption)?;
     {
+        // damm config is the first remaining account, followed by curve variants if pool has curve variant
+        let curve_variant = ctx.accounts.virtual_pool.load()?.curve_variant;
         require!(
-            ctx.remaining_accounts.len() == 1,
+            ctx.remaining_accounts.len() == 1 + usize::from(curve_variant != 0),
             PoolError::MissingPoolConfigInRemainingAccount
         );
         let damm_config_loader: AccountLoader<'_, damm_v2::accounts::Config> =
@@ -486,6 +492,7 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
     }
 
     let mut virtual_pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &virtual_pool, ctx.remaining_accounts)?;
 
     require!(
         virtual_pool.get_migration_progress()? == MigrationProgress::LockedVesting,
@@ -495,7 +502,7 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
     let migration_metadata = ctx.accounts.migration_metadata.load()?;
 
     require!(
-        virtual_pool.is_curve_complete(config.migration_quote_threshold),
+        virtual_pool.is_curve_complete(virtual_pool.get_migration_quote_threshold(&config)),
         PoolError::PoolIsIncompleted
     );
 
@@ -509,16 +516,33 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
     let initial_base_vault_amount = ctx.accounts.base_vault.amount;
 
     let protocol_and_partner_base_fee = virtual_pool.get_protocol_and_trading_base_fee()?;
-    let migration_sqrt_price = config.migration_sqrt_price;
+    let migration_sqrt_price = virtual_pool.get_migration_sqrt_price(&config);
 
-    let MigrationAmount { quote_amount, .. } = config.get_migration_quote_amount_for_config()?;
+    let MigrationAmount { quote_amount, .. } = config.get_migration_quote_amount_for_config(
+        virtual_pool.get_migration_quote_threshold(&config),
+    )?;
     let excluded_fee_base_reserve =
         initial_base_vault_amount.safe_sub(protocol_and_partner_base_fee)?;
 
+    // damm v2 transfers tokens with transfer fee on top, so liquidity is calculated from the amounts the pool receives
+    let base_transfer_fee = get_epoch_transfer_fee(&ctx.accounts.base_mint.to_account_info())?;
+    let quote_transfer_fee = get_epoch_transfer_fee(&ctx.accounts.quote_mint.to_account_info())?;
+    let TransferFeeExcludedAmount {
+        amount: transfer_fee_excluded_base_reserve,
+        ..
+    } = calculate_transfer_fee_excluded_amount(
+        base_transfer_fee.as_ref(),
+        excluded_fee_base_reserve,
+    )?;
+    let TransferFeeExcludedAmount {
+        amount: transfer_fee_excluded_quote_amount,
+        ..
+    } = calculate_transfer_fee_excluded_amount(quote_transfer_fee.as_ref(), quote_amount)?;
+
     // calculate initial liquidity
     let initial_liquidity = get_liquidity_for_adding_liquidity(
-        excluded_fee_base_reserve,
-        quote_amount,
+        transfer_fee_excluded_base_reserve,
+        transfer_fee_excluded_quote_amount,
         migration_sqrt_price,
     )?;
 
@@ -555,7 +579,7 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
     ctx.accounts.create_pool(
         ctx.remaining_accounts[0].clone(),
         first_position_liquidity_distribution.get_total_liquidity()?,
-        config.migration_sqrt_price,
+        migration_sqrt_price,
         const_pda::pool_authority::BUMP,
         migration_fee_option,
         &config,
@@ -583,10 +607,24 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
 
     let updated_excluded_fee_base_reserve =
         excluded_fee_base_reserve.safe_sub(deposited_base_amount)?;
-    let updated_quote_threshold = quote_amount.safe_sub(deposited_quote_amount)?;
-    let liquidity_for_second_position = get_liquidity_for_adding_liquidity(
+    let TransferFeeExcludedAmount {
+        amount: updated_transfer_fee_excluded_base_reserve,
+        ..
+    } = calculate_transfer_fee_excluded_amount(
+        base_transfer_fee.as_ref(),
         updated_excluded_fee_base_reserve,
+    )?;
+    let updated_quote_threshold = quote_amount.safe_sub(deposited_quote_amount)?;
+    let TransferFeeExcludedAmount {
+        amount: updated_transfer_fee_excluded_quote_threshold,
+        ..
+    } = calculate_transfer_fee_excluded_amount(
+        quote_transfer_fee.as_ref(),
         updated_quote_threshold,
+    )?;
+    let liquidity_for_second_position = get_liquidity_for_adding_liquidity(
+        updated_transfer_fee_excluded_base_reserve,
+        updated_transfer_fee_excluded_quote_threshold,
         migration_sqrt_price,
     )?;
 
@@ -605,6 +643,16 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
 
     virtual_pool.update_after_create_pool();
 
+    // base token accounts are thawed by thaw_base_token_account after migration, the last thaw revokes freeze authority
+    if virtual_pool.is_non_transferable() && !virtual_pool.has_frozen_account() {
+        revoke_freeze_authority(
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.base_mint.to_account_info(),
+            ctx.accounts.token_base_program.to_account_info(),
+            const_pda::pool_authority::BUMP,
+        )?;
+    }
+
     // burn the rest of token in pool authority after migrated amount and fee
     ctx.accounts.base_vault.reload()?;
 
@@ -616,7 +664,8 @@ pub fn handle_migrate_damm_v2<'c: 'info, 'info>(
         .amount
         .safe_sub(protocol_and_partner_base_fee)?;
 
-    let burnable_amount = config.get_burnable_amount_post_migration(left_base_token)?;
+    let burnable_amount =
+        virtual_pool.get_burnable_amount_post_migration(&config, left_base_token)?;
 
     if burnable_amount > 0 {
         let seeds = pool_authority_seeds!(const_pda::pool_authority::BUMP);
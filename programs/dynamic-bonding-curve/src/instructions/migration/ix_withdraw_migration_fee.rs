@@ -3,8 +3,11 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::{
-    const_pda,
-    state::{MigrationFeeDistribution, PoolConfig, VirtualPool, CREATOR_MASK, PARTNER_MASK},
+    assert_claim_fee_authority, const_pda, route_partner_quote_to_fee_split,
+    state::{
+        get_pool_config, DelegatedClaimFeeOperator, FeeSplit, MigrationFeeDistribution,
+        OperatorScope, PoolConfig, VirtualPool, CREATOR_MASK, PARTNER_MASK,
+    },
     token::transfer_from_pool,
     EvtWithdrawMigrationFee, PoolError,
 };
@@ -40,8 +43,16 @@ pub struct WithdrawMigrationFeeCtx<'info> {
     /// The mint of quote token
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// partner or creator, or their delegated operator
     pub sender: Signer<'info>,
 
+    /// delegated operator, only required if sender is not partner or creator
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// fee split of config, only required if partner withdraws and config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
+
     /// Token b program
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
@@ -64,29 +75,40 @@ pub enum SenderFlag {
     Creator,
 }
 
-pub fn handle_withdraw_migration_fee(
-    ctx: Context<WithdrawMigrationFeeCtx>,
+pub fn handle_withdraw_migration_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawMigrationFeeCtx<'info>>,
     flag: u8, // 0 as partner and 1 as creator
 ) -> Result<()> {
     let config = ctx.accounts.config.load()?;
     let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
 
     // Make sure pool has been completed
     require!(
-        pool.is_curve_complete(config.migration_quote_threshold),
+        pool.is_curve_complete(pool.get_migration_quote_threshold(&config)),
         PoolError::NotPermitToDoThisAction
     );
     let MigrationFeeDistribution {
         creator_migration_fee,
         partner_migration_fee,
-    } = config.get_migration_fee_distribution()?;
+    } = config.get_migration_fee_distribution(pool.get_migration_quote_threshold(&config))?;
 
     let sender_flag = SenderFlag::try_from(flag).map_err(|_| PoolError::TypeCastFailed)?;
     let fee = if sender_flag == SenderFlag::Partner {
-        require!(
-            ctx.accounts.sender.key() == config.fee_claimer,
-            PoolError::NotPermitToDoThisAction
-        );
+        // fee split vault is validated when routing migration fee
+        let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] = if config.has_fee_split()
+        {
+            &[]
+        } else {
+            &[&ctx.accounts.token_quote_account]
+        };
+        assert_claim_fee_authority(
+            &config.fee_claimer,
+            OperatorScope::Partner,
+            ctx.accounts.sender.key,
+            &ctx.accounts.delegated_operator,
+            receiver_token_accounts,
+        )?;
         let mask = PARTNER_MASK;
         // Ensure the partner has never been withdrawn
         require!(
@@ -95,12 +117,23 @@ pub fn handle_withdraw_migration_fee(
         );
         // update partner withdraw migration fee
         pool.update_withdraw_migration_fee(mask);
+        route_partner_quote_to_fee_split(
+            &ctx.accounts.config.key(),
+            &config,
+            &ctx.accounts.fee_split,
+            &ctx.accounts.quote_mint,
+            &ctx.accounts.token_quote_account,
+            partner_migration_fee,
+        )?;
         partner_migration_fee
     } else {
-        require!(
-            ctx.accounts.sender.key() == pool.creator,
-            PoolError::NotPermitToDoThisAction
-        );
+        assert_claim_fee_authority(
+            &pool.creator,
+            OperatorScope::Creator,
+            ctx.accounts.sender.key,
+            &ctx.accounts.delegated_operator,
+            &[&ctx.accounts.token_quote_account],
+        )?;
         let mask = CREATOR_MASK;
         // Ensure the creator has never been withdrawn
         require!(
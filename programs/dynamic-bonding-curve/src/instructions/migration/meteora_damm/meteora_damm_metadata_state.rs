@@ -151,6 +151,14 @@ impl MeteoraDammMigrationMetadata {
         self.partner_claim_status = 1;
     }
 
+    /// every lp of partner and creator is locked or claimed
+    pub fn is_lp_distributed(&self) -> bool {
+        (self.partner_locked_lp == 0 || self.is_partner_lp_locked())
+            && (self.creator_locked_lp == 0 || self.is_creator_lp_locked())
+            && (self.partner_lp == 0 || self.is_partner_claim_lp())
+            && (self.creator_lp == 0 || self.is_creator_claim_lp())
+    }
+
     pub fn is_creator_lp_locked(&self) -> bool {
         self.creator_locked_status == 1
     }
@@ -6,9 +6,10 @@ use crate::{
     params::fee_parameters::to_bps,
     safe_math::SafeMath,
     state::{
-        MigrationAmount, MigrationFeeOption, MigrationOption, MigrationProgress, PoolConfig,
-        VirtualPool,
+        get_pool_config, MigrationAmount, MigrationFeeOption, MigrationOption, MigrationProgress,
+        PoolConfig, VirtualPool,
     },
+    token::revoke_freeze_authority,
     *,
 };
 
@@ -211,14 +212,15 @@ impl<'info> MigrateMeteoraDammCtx<'info> {
     }
 }
 
-pub fn handle_migrate_meteora_damm<'info>(
-    ctx: Context<'_, '_, '_, 'info, MigrateMeteoraDammCtx<'info>>,
+pub fn handle_migrate_meteora_damm<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, MigrateMeteoraDammCtx<'info>>,
 ) -> Result<()> {
     let config = ctx.accounts.config.load()?;
     ctx.accounts
         .validate_config_key(config.migration_fee_option)?;
 
     let mut virtual_pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &virtual_pool, ctx.remaining_accounts)?;
     require!(
         virtual_pool.get_migration_progress()? == MigrationProgress::LockedVesting,
         PoolError::NotPermitToDoThisAction
@@ -227,7 +229,7 @@ pub fn handle_migrate_meteora_damm<'info>(
     let mut migration_metadata = ctx.accounts.migration_metadata.load_mut()?;
 
     require!(
-        virtual_pool.is_curve_complete(config.migration_quote_threshold),
+        virtual_pool.is_curve_complete(virtual_pool.get_migration_quote_threshold(&config)),
         PoolError::PoolIsIncompleted
     );
 
@@ -237,14 +239,26 @@ pub fn handle_migrate_meteora_damm<'info>(
         migration_option == MigrationOption::MeteoraDamm,
         PoolError::InvalidMigrationOption
     );
-    let base_reserve = config.migration_base_threshold;
-    let MigrationAmount { quote_amount, .. } = config.get_migration_quote_amount_for_config()?;
+    let base_reserve = virtual_pool.get_migration_base_threshold(&config)?;
+    let MigrationAmount { quote_amount, .. } = config.get_migration_quote_amount_for_config(
+        virtual_pool.get_migration_quote_threshold(&config),
+    )?;
 
     ctx.accounts
         .create_pool(base_reserve, quote_amount, const_pda::pool_authority::BUMP)?;
 
     virtual_pool.update_after_create_pool();
 
+    // base token accounts are thawed by thaw_base_token_account after migration, the last thaw revokes freeze authority
+    if virtual_pool.is_non_transferable() && !virtual_pool.has_frozen_account() {
+        revoke_freeze_authority(
+            ctx.accounts.pool_authority.to_account_info(),
+            ctx.accounts.token_a_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            const_pda::pool_authority::BUMP,
+        )?;
+    }
+
     // burn the rest of token in pool authority after migrated amount and fee
     ctx.accounts.base_vault.reload()?;
     let left_base_token = ctx
@@ -253,7 +267,8 @@ pub fn handle_migrate_meteora_damm<'info>(
         .amount
         .safe_sub(virtual_pool.get_protocol_and_trading_base_fee()?)?;
 
-    let burnable_amount = config.get_burnable_amount_post_migration(left_base_token)?;
+    let burnable_amount =
+        virtual_pool.get_burnable_amount_post_migration(&config, left_base_token)?;
     if burnable_amount > 0 {
         let seeds = pool_authority_seeds!(const_pda::pool_authority::BUMP);
         anchor_spl::token::burn(
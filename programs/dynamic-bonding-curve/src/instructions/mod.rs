@@ -2,6 +2,16 @@ pub mod admin;
 pub use admin::*;
 pub mod ix_swap;
 pub use ix_swap::*;
+pub mod ix_complete_curve_at_deadline;
+pub use ix_complete_curve_at_deadline::*;
+pub mod ix_distribute_surplus;
+pub use ix_distribute_surplus::*;
+pub mod ix_close_virtual_pool;
+pub use ix_close_virtual_pool::*;
+pub mod ix_thaw_base_token_account;
+pub use ix_thaw_base_token_account::*;
+pub mod ix_update_token_metadata;
+pub use ix_update_token_metadata::*;
 pub mod initialize_pool;
 pub use initialize_pool::*;
 pub mod partner;
@@ -10,3 +20,5 @@ pub mod migration;
 pub use migration::*;
 pub mod creator;
 pub use creator::*;
+pub mod delegated_operator;
+pub use delegated_operator::*;
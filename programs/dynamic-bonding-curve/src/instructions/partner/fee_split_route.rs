@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    state::{FeeSplit, PoolConfig},
+    token::{calculate_transfer_fee_excluded_amount, get_epoch_transfer_fee},
+    PoolError,
+};
+
+/// if config has fee split, partner quote revenue must be sent to fee split vault and credited to its recipients,
+/// recipients are credited with the amount fee split vault receives after transfer fee of quote mint
+pub fn route_partner_quote_to_fee_split<'info>(
+    config_key: &Pubkey,
+    config: &PoolConfig,
+    fee_split: &Option<AccountLoader<'info, FeeSplit>>,
+    quote_mint: &InterfaceAccount<'info, Mint>,
+    receiver_token_account: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    if !config.has_fee_split() {
+        return Ok(());
+    }
+
+    let transfer_fee_excluded_amount = calculate_transfer_fee_excluded_amount(
+        get_epoch_transfer_fee(&quote_mint.to_account_info())?.as_ref(),
+        amount,
+    )?
+    .amount;
+    credit_partner_quote_to_fee_split(
+        config_key,
+        config,
+        fee_split,
+        receiver_token_account,
+        transfer_fee_excluded_amount,
+    )
+}
+
+/// credit recipients of fee split with the amount fee split vault received, after transfer fee of quote mint
+pub fn credit_partner_quote_to_fee_split<'info>(
+    config_key: &Pubkey,
+    config: &PoolConfig,
+    fee_split: &Option<AccountLoader<'info, FeeSplit>>,
+    receiver_token_account: &InterfaceAccount<'info, TokenAccount>,
+    transfer_fee_excluded_amount: u64,
+) -> Result<()> {
+    if !config.has_fee_split() {
+        return Ok(());
+    }
+
+    let mut fee_split = fee_split
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?
+        .load_mut()?;
+    require!(
+        fee_split.config.eq(config_key) && fee_split.quote_vault.eq(&receiver_token_account.key()),
+        PoolError::InvalidAccount
+    );
+    fee_split.distribute(transfer_fee_excluded_amount, 0)
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    assert_claim_fee_authority, const_pda, credit_partner_quote_to_fee_split,
+    safe_math::SafeMath,
+    state::{DelegatedClaimFeeOperator, FeeSplit, OperatorScope, PoolConfig, VirtualPool},
+    token::{
+        calculate_transfer_fee_excluded_amount_of_transfers, get_epoch_transfer_fee,
+        get_token_program_flags, transfer_from_pool, TokenProgramFlags,
+    },
+    EvtBatchClaimTradingFee, PoolError,
+};
+
+/// number of remaining accounts for each pool: pool, base mint, base vault, quote vault, treasury token a account
+const ACCOUNTS_PER_POOL: usize = 5;
+
+/// Accounts for partner to claim trading fees of many pools in the same config
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchClaimTradingFeesCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    /// The treasury token b account
+    #[account(mut)]
+    pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token b
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// fee claimer or its delegated operator
+    pub signer: Signer<'info>,
+
+    /// delegated operator of fee claimer, only required if signer is not fee claimer
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// fee split of config, only required if config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+
+    /// Token program of spl token base mints
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of token 2022 base mints
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_2022_program: Interface<'info, TokenInterface>,
+}
+
+/// Partner claim base and quote fees of pools passed in remaining accounts, base fee of each pool is sent with the token program of its base mint.
+/// Quote fee is sent to fee split vault if config has fee split.
+pub fn handle_batch_claim_trading_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, BatchClaimTradingFeesCtx<'info>>,
+) -> Result<()> {
+    let config = ctx.accounts.config.load()?;
+    // fee split vault is validated when routing quote fees
+    let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] = if config.has_fee_split() {
+        &[]
+    } else {
+        &[&ctx.accounts.token_b_account]
+    };
+    assert_claim_fee_authority(
+        &config.fee_claimer,
+        OperatorScope::Partner,
+        ctx.accounts.signer.key,
+        &ctx.accounts.delegated_operator,
+        receiver_token_accounts,
+    )?;
+    let is_fee_claimer = ctx.accounts.signer.key.eq(&config.fee_claimer);
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % ACCOUNTS_PER_POOL == 0,
+        PoolError::InvalidAccount
+    );
+
+    let mut total_quote_amount: u64 = 0;
+    let mut token_quote_amounts: Vec<u64> =
+        Vec::with_capacity(remaining_accounts.len() / ACCOUNTS_PER_POOL);
+    for accounts in remaining_accounts.chunks(ACCOUNTS_PER_POOL) {
+        let pool_loader: AccountLoader<'info, VirtualPool> = AccountLoader::try_from(&accounts[0])?;
+        let base_mint: InterfaceAccount<'info, Mint> = InterfaceAccount::try_from(&accounts[1])?;
+        let base_vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[2])?;
+        let quote_vault: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[3])?;
+        let token_a_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&accounts[4])?;
+
+        // delegated operator can only send base fee to fee claimer
+        require!(
+            is_fee_claimer || token_a_account.owner.eq(&config.fee_claimer),
+            PoolError::InvalidAccount
+        );
+
+        let (token_base_amount, token_quote_amount) =
+            pool_loader.load_mut()?.batch_claim_partner_trading_fee(
+                &ctx.accounts.config.key(),
+                &base_mint.key(),
+                &base_vault.key(),
+                &quote_vault.key(),
+            )?;
+
+        if token_base_amount > 0 {
+            let token_base_program = match get_token_program_flags(&base_mint) {
+                TokenProgramFlags::TokenProgram => &ctx.accounts.token_program,
+                TokenProgramFlags::TokenProgram2022 => &ctx.accounts.token_2022_program,
+            };
+            transfer_from_pool(
+                ctx.accounts.pool_authority.to_account_info(),
+                &base_mint,
+                &base_vault,
+                &token_a_account,
+                token_base_program,
+                token_base_amount,
+                const_pda::pool_authority::BUMP,
+            )?;
+        }
+
+        if token_quote_amount > 0 {
+            transfer_from_pool(
+                ctx.accounts.pool_authority.to_account_info(),
+                &ctx.accounts.quote_mint,
+                &quote_vault,
+                &ctx.accounts.token_b_account,
+                &ctx.accounts.token_quote_program,
+                token_quote_amount,
+                const_pda::pool_authority::BUMP,
+            )?;
+            total_quote_amount = total_quote_amount.safe_add(token_quote_amount)?;
+            token_quote_amounts.push(token_quote_amount);
+        }
+    }
+
+    // quote fee of each pool is sent in its own transfer, which pays its own transfer fee
+    let fee_split_amount = calculate_transfer_fee_excluded_amount_of_transfers(
+        get_epoch_transfer_fee(&ctx.accounts.quote_mint.to_account_info())?.as_ref(),
+        &token_quote_amounts,
+    )?;
+    credit_partner_quote_to_fee_split(
+        &ctx.accounts.config.key(),
+        &config,
+        &ctx.accounts.fee_split,
+        &ctx.accounts.token_b_account,
+        fee_split_amount,
+    )?;
+
+    emit_cpi!(EvtBatchClaimTradingFee {
+        config: ctx.accounts.config.key(),
+        number_of_pools: (remaining_accounts.len() / ACCOUNTS_PER_POOL) as u64,
+        token_quote_amount: total_quote_amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    state::{FeeSplit, PoolConfig},
+    token::transfer_from_pool,
+    EvtClaimFeeSplit,
+};
+
+/// Accounts for fee split recipient to claim its share of partner revenue
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimFeeSplitCtx<'info> {
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(mut, has_one = config, has_one = quote_vault)]
+    pub fee_split: AccountLoader<'info, FeeSplit>,
+
+    /// The vault token account of fee split
+    #[account(mut, token::token_program = token_quote_program, token::mint = quote_mint)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The receiver token account
+    #[account(mut)]
+    pub token_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of quote token
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// receives pool creation fee paid in SOL
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_claim_fee_split(ctx: Context<ClaimFeeSplitCtx>) -> Result<()> {
+    let (amount, sol_amount) = ctx
+        .accounts
+        .fee_split
+        .load_mut()?
+        .claim(ctx.accounts.recipient.key)?;
+
+    transfer_from_pool(
+        ctx.accounts.pool_authority.to_account_info(),
+        &ctx.accounts.quote_mint,
+        &ctx.accounts.quote_vault,
+        &ctx.accounts.token_quote_account,
+        &ctx.accounts.token_quote_program,
+        amount,
+        const_pda::pool_authority::BUMP,
+    )?;
+
+    // fee split is owned by the program, pending lamports are on top of its rent
+    if sol_amount > 0 {
+        ctx.accounts.fee_split.sub_lamports(sol_amount)?;
+        ctx.accounts.recipient.add_lamports(sol_amount)?;
+    }
+
+    emit_cpi!(EvtClaimFeeSplit {
+        fee_split: ctx.accounts.fee_split.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        sol_amount,
+    });
+    Ok(())
+}
@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda,
-    state::{PoolConfig, VirtualPool},
+    assert_claim_fee_authority, const_pda, route_partner_quote_to_fee_split,
+    state::{DelegatedClaimFeeOperator, FeeSplit, OperatorScope, PoolConfig, VirtualPool},
     token::transfer_from_pool,
     EvtClaimTradingFee,
 };
@@ -18,7 +18,7 @@ pub struct ClaimTradingFeesCtx<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
 
-    #[account(has_one=quote_mint, has_one=fee_claimer)]
+    #[account(has_one=quote_mint)]
     pub config: AccountLoader<'info, PoolConfig>,
 
     #[account(
@@ -52,7 +52,15 @@ pub struct ClaimTradingFeesCtx<'info> {
     /// The mint of token b
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub fee_claimer: Signer<'info>,
+    /// fee claimer or its delegated operator
+    pub signer: Signer<'info>,
+
+    /// delegated operator of fee claimer, only required if signer is not fee claimer
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// fee split of config, only required if config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
 
     /// Token a program
     pub token_base_program: Interface<'info, TokenInterface>,
@@ -61,16 +69,40 @@ pub struct ClaimTradingFeesCtx<'info> {
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
 
-/// Partner claim fees.
+/// Partner claim fees, quote fee is sent to fee split vault if config has fee split.
 pub fn handle_claim_trading_fee(
     ctx: Context<ClaimTradingFeesCtx>,
     max_base_amount: u64,
     max_quote_amount: u64,
 ) -> Result<()> {
+    let config = ctx.accounts.config.load()?;
+    // fee split vault is validated when routing quote fee
+    let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] = if config.has_fee_split() {
+        &[&ctx.accounts.token_a_account]
+    } else {
+        &[&ctx.accounts.token_a_account, &ctx.accounts.token_b_account]
+    };
+    assert_claim_fee_authority(
+        &config.fee_claimer,
+        OperatorScope::Partner,
+        ctx.accounts.signer.key,
+        &ctx.accounts.delegated_operator,
+        receiver_token_accounts,
+    )?;
+
     let mut pool = ctx.accounts.pool.load_mut()?;
     let (token_base_amount, token_quote_amount) =
         pool.claim_partner_trading_fee(max_base_amount, max_quote_amount)?;
 
+    route_partner_quote_to_fee_split(
+        &ctx.accounts.config.key(),
+        &config,
+        &ctx.accounts.fee_split,
+        &ctx.accounts.quote_mint,
+        &ctx.accounts.token_b_account,
+        token_quote_amount,
+    )?;
+
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
         &ctx.accounts.base_mint,
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    state::{CurveVariants, FeeSplit, PoolConfig, TokenMetadataConfig},
+    token::{close_pool_token_account, harvest_withheld_transfer_fee},
+    EvtCloseConfig, PoolError,
+};
+
+/// Accounts for partner to close a config that no pool references
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseConfigCtx<'info> {
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = fee_claimer,
+    )]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    /// curve variants of config, only required if config has curve variants, closed together with config
+    #[account(mut, has_one = config)]
+    pub curve_variants: Option<AccountLoader<'info, CurveVariants>>,
+
+    /// token metadata config of config, only required if config has token metadata config, closed together with config
+    #[account(mut, has_one = config)]
+    pub token_metadata_config: Option<AccountLoader<'info, TokenMetadataConfig>>,
+
+    pub fee_claimer: Signer<'info>,
+
+    /// CHECK: rent receiver
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// fee split of config, only required if config has fee split, closed together with config after recipients claimed everything
+    #[account(mut, has_one = config)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
+
+    /// vault of fee split, only required if config has fee split, must be empty
+    #[account(mut)]
+    pub fee_split_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: pool authority, only required if config has fee split
+    #[account(address = const_pda::pool_authority::ID)]
+    pub pool_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote mint, only required if transfer fee is withheld in fee split vault, it is harvested to the mint before closing
+    #[account(mut, address = config.load()?.quote_mint)]
+    pub quote_mint: Option<UncheckedAccount<'info>>,
+
+    /// Token quote program, only required if config has fee split
+    pub token_quote_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handle_close_config(ctx: Context<CloseConfigCtx>) -> Result<()> {
+    let config = ctx.accounts.config.load()?;
+    require!(config.has_no_pool(), PoolError::NotPermitToDoThisAction);
+
+    if config.has_curve_variants() {
+        let curve_variants = ctx
+            .accounts
+            .curve_variants
+            .as_ref()
+            .ok_or(PoolError::InvalidCurveVariant)?;
+        curve_variants.close(ctx.accounts.rent_receiver.to_account_info())?;
+    }
+
+    if config.has_token_metadata_config() {
+        let token_metadata_config = ctx
+            .accounts
+            .token_metadata_config
+            .as_ref()
+            .ok_or(PoolError::InvalidTokenMetadataConfig)?;
+        token_metadata_config.close(ctx.accounts.rent_receiver.to_account_info())?;
+    }
+
+    if config.has_fee_split() {
+        close_fee_split(&ctx)?;
+    }
+
+    emit_cpi!(EvtCloseConfig {
+        config: ctx.accounts.config.key(),
+        rent_receiver: ctx.accounts.rent_receiver.key(),
+    });
+    Ok(())
+}
+
+fn close_fee_split(ctx: &Context<CloseConfigCtx>) -> Result<()> {
+    let fee_split = ctx
+        .accounts
+        .fee_split
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?;
+    let fee_split_vault = ctx
+        .accounts
+        .fee_split_vault
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?;
+    let pool_authority = ctx
+        .accounts
+        .pool_authority
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?;
+    let token_quote_program = ctx
+        .accounts
+        .token_quote_program
+        .as_ref()
+        .ok_or(PoolError::InvalidAccount)?;
+    {
+        let fee_split = fee_split.load()?;
+        require!(
+            fee_split.quote_vault.eq(&fee_split_vault.key()),
+            PoolError::InvalidAccount
+        );
+        require!(
+            fee_split.is_fully_claimed(),
+            PoolError::NotPermitToDoThisAction
+        );
+    }
+
+    // withheld transfer fees must be harvested before closing vault
+    harvest_withheld_transfer_fee(
+        fee_split_vault,
+        ctx.accounts.quote_mint.as_ref(),
+        token_quote_program,
+    )?;
+    // token program rejects closing vault that is not empty
+    close_pool_token_account(
+        pool_authority.to_account_info(),
+        fee_split_vault,
+        ctx.accounts.rent_receiver.to_account_info(),
+        token_quote_program,
+        const_pda::pool_authority::BUMP,
+    )?;
+    fee_split.close(ctx.accounts.rent_receiver.to_account_info())
+}
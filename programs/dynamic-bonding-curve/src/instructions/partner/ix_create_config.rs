@@ -5,9 +5,10 @@ use static_assertions::const_assert_eq;
 
 use crate::{
     activation_handler::ActivationType,
+    const_pda,
     constants::{
-        MAX_CURVE_POINT, MAX_MIGRATED_POOL_FEE_BPS, MAX_SQRT_PRICE, MIN_MIGRATED_POOL_FEE_BPS,
-        MIN_SQRT_PRICE,
+        MAX_BASE_TRANSFER_FEE_BPS, MAX_CURVE_POINT, MAX_MIGRATED_POOL_FEE_BPS, MAX_SQRT_PRICE,
+        MIN_MIGRATED_POOL_FEE_BPS, MIN_SQRT_PRICE,
     },
     params::{
         fee_parameters::PoolFeeParameters,
@@ -18,8 +19,9 @@ use crate::{
     },
     safe_math::SafeMath,
     state::{
-        CollectFeeMode, LockedVestingConfig, MigrationFeeOption, MigrationOption, PoolConfig,
-        TokenAuthorityOption, TokenType,
+        BaseTransferFeeAuthority, CollectFeeMode, GlobalState, LockedVestingConfig,
+        MigrationFeeOption, MigrationOption, PoolConfig, PoolConfigInitParams,
+        PoolCreationFeeToken, ProtocolConfig, TokenAuthorityOption, TokenType,
     },
     token::{get_token_program_flags, is_supported_quote_mint},
     DammV2DynamicFee, EvtCreateConfig, EvtCreateConfigV2, PoolError,
@@ -46,8 +48,36 @@ pub struct ConfigParameters {
     pub token_update_authority: u8,
     pub migration_fee: MigrationFee,
     pub migrated_pool_fee: MigratedPoolFee,
+    /// minimum quote amount that must be raised for the pool to complete at the migration deadline
+    pub min_migration_quote_threshold: u64,
+    /// duration from the activation point to the migration deadline, 0 means no deadline
+    pub migration_deadline_duration: u64,
+    /// max price change in bps within the circuit breaker window, 0 means circuit breaker is disabled
+    pub circuit_breaker_max_price_change_bps: u16,
+    /// circuit breaker window in seconds, must be 0 if dynamic fee is enabled
+    pub circuit_breaker_window: u16,
+    /// partner and creator share of surplus in percentage, must be an approved tier in protocol config, 0 means default share
+    pub partner_and_creator_surplus_share: u8,
+    /// creator percentage of partner and creator surplus, must be 0 if surplus share is 0
+    pub creator_surplus_percentage: u8,
+    /// token of pool creation fee, 0: SOL, 1: quote token
+    pub pool_creation_fee_token: u8,
+    /// padding for alignment
+    pub padding_0: [u8; 1],
+    /// fee paid by pool creator when a pool is initialized on the config, 0 means no fee
+    pub pool_creation_fee: u64,
+    /// transfer fee of token 2022 base mint in bps, 0 means base mint has no transfer fee
+    pub base_transfer_fee_bps: u16,
+    /// owner of withheld transfer fees of base mint, 0: creator, 1: partner
+    pub base_transfer_fee_authority: u8,
+    /// 1 means base token can only move through swaps until migration, trading fee must be collected in quote token
+    pub non_transferable_until_migration: u8,
+    /// padding for alignment
+    pub padding_1: [u8; 4],
+    /// max transfer fee of base mint per transfer
+    pub base_max_transfer_fee: u64,
     /// padding for future use
-    pub padding: [u64; 7],
+    pub padding: [u64; 1],
     pub curve: Vec<LiquidityDistributionParameters>,
 }
 
@@ -274,42 +304,245 @@ impl ConfigParameters {
             PoolError::InvalidQuoteThreshold
         );
 
-        // validate vesting params
-        self.locked_vesting.validate()?;
+        // validate migration deadline
+        if self.migration_deadline_duration > 0 {
+            require!(
+                self.min_migration_quote_threshold > 0
+                    && self.min_migration_quote_threshold <= self.migration_quote_threshold,
+                PoolError::InvalidQuoteThreshold
+            );
+        } else {
+            require!(
+                self.min_migration_quote_threshold == 0,
+                PoolError::InvalidQuoteThreshold
+            );
+        }
 
-        // validate price and liquidity
-        require!(
-            self.sqrt_start_price >= MIN_SQRT_PRICE && self.sqrt_start_price < MAX_SQRT_PRICE,
-            PoolError::InvalidCurve
-        );
-        let curve_length = self.curve.len();
-        require!(
-            curve_length > 0 && curve_length <= MAX_CURVE_POINT,
-            PoolError::InvalidCurve
-        );
-        require!(
-            self.curve[0].sqrt_price > self.sqrt_start_price
-                && self.curve[0].liquidity > 0
-                && self.curve[0].sqrt_price <= MAX_SQRT_PRICE,
-            PoolError::InvalidCurve
-        );
+        // validate circuit breaker, dynamic fee references are used if dynamic fee is enabled
+        if self.circuit_breaker_max_price_change_bps == 0 || self.pool_fees.dynamic_fee.is_some() {
+            require!(
+                self.circuit_breaker_window == 0,
+                PoolError::InvalidCircuitBreaker
+            );
+        } else {
+            require!(
+                self.circuit_breaker_window > 0,
+                PoolError::InvalidCircuitBreaker
+            );
+        }
 
-        for i in 1..curve_length {
+        // validate surplus share, surplus is split by creator trading fee percentage if surplus share is not set
+        if self.partner_and_creator_surplus_share == 0 {
             require!(
-                self.curve[i].sqrt_price > self.curve[i - 1].sqrt_price
-                    && self.curve[i].liquidity > 0,
-                PoolError::InvalidCurve
+                self.creator_surplus_percentage == 0,
+                PoolError::InvalidSurplusShare
+            );
+        } else {
+            require!(
+                self.partner_and_creator_surplus_share <= 100
+                    && self.creator_surplus_percentage <= 100,
+                PoolError::InvalidSurplusShare
             );
         }
 
-        // the last price in curve must be smaller than or equal max price
+        // validate pool creation fee
+        let pool_creation_fee_token = PoolCreationFeeToken::try_from(self.pool_creation_fee_token)
+            .map_err(|_| PoolError::InvalidPoolCreationFee)?;
+        if self.pool_creation_fee == 0 {
+            require!(
+                pool_creation_fee_token == PoolCreationFeeToken::Sol,
+                PoolError::InvalidPoolCreationFee
+            );
+        }
+
+        // validate base transfer fee, only token 2022 base mint can have transfer fee
+        if self.base_transfer_fee_bps == 0 {
+            require!(
+                self.base_max_transfer_fee == 0 && self.base_transfer_fee_authority == 0,
+                PoolError::InvalidBaseTransferFee
+            );
+        } else {
+            require!(
+                token_type_value == TokenType::Token2022
+                    && self.base_transfer_fee_bps <= MAX_BASE_TRANSFER_FEE_BPS
+                    && self.base_max_transfer_fee > 0
+                    && BaseTransferFeeAuthority::try_from(self.base_transfer_fee_authority).is_ok(),
+                PoolError::InvalidBaseTransferFee
+            );
+        }
+
+        if self.non_transferable_until_migration != 0 {
+            validate_non_transferable_until_migration(
+                self.non_transferable_until_migration,
+                self.collect_fee_mode,
+                self.token_update_authority,
+                &self.locked_vesting,
+            )?;
+        }
+
+        // validate vesting params
+        self.locked_vesting.validate()?;
+
+        // validate price and liquidity
+        validate_curve(self.sqrt_start_price, &self.curve)?;
+
+        Ok(())
+    }
+}
+
+/// curve must start above the start price, with increasing prices and non-zero liquidity
+/// base token of a non-transferable config can only move through swaps until migration,
+/// so base token must not reach accounts that are never frozen
+pub fn validate_non_transferable_until_migration(
+    non_transferable_until_migration: u8,
+    collect_fee_mode: u8,
+    token_update_authority: u8,
+    locked_vesting: &LockedVestingParams,
+) -> Result<()> {
+    // base token received as trading fee could be transferred freely before migration
+    require!(
+        non_transferable_until_migration == 1
+            && collect_fee_mode == u8::from(CollectFeeMode::QuoteToken),
+        PoolError::InvalidNonTransferableOption
+    );
+    // mint authority could mint base token to accounts that are never frozen
+    let token_authority = TokenAuthorityOption::try_from(token_update_authority)
+        .map_err(|_| PoolError::InvalidTokenAuthorityOption)?;
+    require!(
+        !matches!(
+            token_authority,
+            TokenAuthorityOption::CreatorUpdateAndMintAuthority
+                | TokenAuthorityOption::PartnerUpdateAndMintAuthority
+        ),
+        PoolError::InvalidNonTransferableOption
+    );
+    // locked vesting is sent to an escrow that is never frozen, and could be claimed before migration without cliff
+    require!(
+        !locked_vesting.has_vesting(),
+        PoolError::InvalidNonTransferableOption
+    );
+    Ok(())
+}
+
+pub fn validate_curve(
+    sqrt_start_price: u128,
+    curve: &[LiquidityDistributionParameters],
+) -> Result<()> {
+    require!(
+        (MIN_SQRT_PRICE..MAX_SQRT_PRICE).contains(&sqrt_start_price),
+        PoolError::InvalidCurve
+    );
+    let curve_length = curve.len();
+    require!(
+        curve_length > 0 && curve_length <= MAX_CURVE_POINT,
+        PoolError::InvalidCurve
+    );
+    require!(
+        curve[0].sqrt_price > sqrt_start_price
+            && curve[0].liquidity > 0
+            && curve[0].sqrt_price <= MAX_SQRT_PRICE,
+        PoolError::InvalidCurve
+    );
+
+    for i in 1..curve_length {
         require!(
-            self.curve[curve_length - 1].sqrt_price <= MAX_SQRT_PRICE,
+            curve[i].sqrt_price > curve[i - 1].sqrt_price && curve[i].liquidity > 0,
             PoolError::InvalidCurve
         );
-
-        Ok(())
     }
+
+    // the last price in curve must be smaller than or equal max price
+    require!(
+        curve[curve_length - 1].sqrt_price <= MAX_SQRT_PRICE,
+        PoolError::InvalidCurve
+    );
+
+    Ok(())
+}
+
+pub struct CurveAmounts {
+    pub migration_sqrt_price: u128,
+    pub swap_base_amount: u64,
+    pub migration_base_amount: u64,
+}
+
+/// base token amounts that are sold on the curve and migrated, curve must be validated
+pub fn get_curve_amounts(
+    migration_quote_threshold: u64,
+    sqrt_start_price: u128,
+    curve: &[LiquidityDistributionParameters],
+    migration_fee_percentage: u8,
+    migration_option: u8,
+) -> Result<CurveAmounts> {
+    let migration_sqrt_price =
+        get_migration_threshold_price(migration_quote_threshold, sqrt_start_price, curve)?;
+    // migration price must be smaller than max sqrt price
+    require!(
+        migration_sqrt_price < MAX_SQRT_PRICE,
+        PoolError::InvalidCurve
+    );
+
+    let swap_base_amount_256 =
+        get_base_token_for_swap(sqrt_start_price, migration_sqrt_price, curve)?;
+    let swap_base_amount: u64 = swap_base_amount_256
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?;
+
+    let migration_base_amount = get_migration_base_token(
+        migration_quote_threshold,
+        migration_fee_percentage,
+        migration_sqrt_price,
+        MigrationOption::try_from(migration_option)
+            .map_err(|_| PoolError::InvalidMigrationOption)?,
+    )?;
+
+    require!(
+        // this is fine to add redundant check
+        migration_base_amount > 0 && swap_base_amount > 0,
+        PoolError::InvalidCurve
+    );
+
+    Ok(CurveAmounts {
+        migration_sqrt_price,
+        swap_base_amount,
+        migration_base_amount,
+    })
+}
+
+/// fixed token supply must cover the curve, migration and locked vesting
+pub fn validate_fixed_token_supply(
+    curve_amounts: &CurveAmounts,
+    sqrt_start_price: u128,
+    curve: &[LiquidityDistributionParameters],
+    locked_vesting: &LockedVestingParams,
+    pre_migration_token_supply: u64,
+    post_migration_token_supply: u64,
+) -> Result<()> {
+    let swap_base_amount_buffer = PoolConfig::get_swap_amount_with_buffer(
+        curve_amounts.swap_base_amount,
+        sqrt_start_price,
+        curve,
+    )?;
+
+    let minimum_base_supply_with_buffer = PoolConfig::get_total_token_supply(
+        swap_base_amount_buffer,
+        curve_amounts.migration_base_amount,
+        locked_vesting,
+    )?;
+
+    let minimum_base_supply_without_buffer = PoolConfig::get_total_token_supply(
+        curve_amounts.swap_base_amount,
+        curve_amounts.migration_base_amount,
+        locked_vesting,
+    )?;
+
+    require!(
+        minimum_base_supply_without_buffer <= post_migration_token_supply
+            && post_migration_token_supply <= pre_migration_token_supply
+            && minimum_base_supply_with_buffer <= pre_migration_token_supply,
+        PoolError::InvalidTokenSupply
+    );
+    Ok(())
 }
 
 #[event_cpi]
@@ -334,145 +567,132 @@ pub struct CreateConfigCtx<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// global state, instruction is rejected when emergency stop is on
+    #[account(
+        address = const_pda::global_state::ID,
+        constraint = !global_state.load()?.is_emergency_stopped() @ PoolError::EmergencyStopped,
+    )]
+    pub global_state: AccountLoader<'info, GlobalState>,
+
+    /// protocol config, surplus share of config must be approved by admin
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
 }
 
-pub fn handle_create_config(
-    ctx: Context<CreateConfigCtx>,
-    config_parameters: ConfigParameters,
+/// validate config parameters and write them to config, used by create_config and update_config
+pub fn process_init_config(
+    config: &mut PoolConfig,
+    config_parameters: &ConfigParameters,
+    quote_mint: &InterfaceAccount<'_, Mint>,
+    fee_claimer: &Pubkey,
+    leftover_receiver: &Pubkey,
+    protocol_config: &ProtocolConfig,
 ) -> Result<()> {
-    config_parameters.validate(&ctx.accounts.quote_mint)?;
+    config_parameters.validate(quote_mint)?;
 
     let ConfigParameters {
-        pool_fees,
-        collect_fee_mode,
         migration_option,
-        activation_type,
-        token_type,
-        token_decimal,
-        partner_lp_percentage,
-        partner_locked_lp_percentage,
-        creator_lp_percentage,
-        creator_locked_lp_percentage,
         migration_quote_threshold,
         sqrt_start_price,
         locked_vesting,
-        migration_fee_option,
         token_supply,
         curve,
-        creator_trading_fee_percentage,
-        token_update_authority,
         migration_fee,
-        migrated_pool_fee,
+        partner_and_creator_surplus_share,
+        pool_creation_fee,
         ..
-    } = config_parameters.clone();
-
-    let sqrt_migration_price =
-        get_migration_threshold_price(migration_quote_threshold, sqrt_start_price, &curve)?;
-    // migration price must be smaller than max sqrt price
-    require!(
-        sqrt_migration_price < MAX_SQRT_PRICE,
-        PoolError::InvalidCurve
-    );
+    } = config_parameters;
 
-    let swap_base_amount_256 =
-        get_base_token_for_swap(sqrt_start_price, sqrt_migration_price, &curve)?;
-    let swap_base_amount: u64 = swap_base_amount_256
-        .try_into()
-        .map_err(|_| PoolError::TypeCastFailed)?;
-
-    let migration_base_amount = get_migration_base_token(
-        migration_quote_threshold,
+    if *partner_and_creator_surplus_share != 0 {
+        require!(
+            protocol_config.is_approved_surplus_share(*partner_and_creator_surplus_share),
+            PoolError::InvalidSurplusShare
+        );
+    }
+    // protocol cut is fixed when config is created or updated
+    let pool_creation_fee_protocol_percent = if *pool_creation_fee > 0 {
+        protocol_config.pool_creation_fee_protocol_percent
+    } else {
+        0
+    };
+
+    let curve_amounts = get_curve_amounts(
+        *migration_quote_threshold,
+        *sqrt_start_price,
+        curve,
         migration_fee.fee_percentage,
-        sqrt_migration_price,
-        MigrationOption::try_from(migration_option)
-            .map_err(|_| PoolError::InvalidMigrationOption)?,
+        *migration_option,
     )?;
 
-    require!(
-        // this is fine to add redundant check
-        migration_base_amount > 0 && swap_base_amount > 0,
-        PoolError::InvalidCurve
-    );
-
-    let (fixed_token_supply_flag, pre_migration_token_supply, post_migration_token_supply) =
-        if let Some(TokenSupplyParams {
-            pre_migration_token_supply,
-            post_migration_token_supply,
-        }) = token_supply
-        {
-            let swap_base_amount_buffer = PoolConfig::get_swap_amount_with_buffer(
-                swap_base_amount,
-                sqrt_start_price,
-                &curve,
-            )?;
-
-            let minimum_base_supply_with_buffer = PoolConfig::get_total_token_supply(
-                swap_base_amount_buffer,
-                migration_base_amount,
-                &locked_vesting,
-            )?;
-
-            let minimum_base_supply_without_buffer = PoolConfig::get_total_token_supply(
-                swap_base_amount,
-                migration_base_amount,
-                &locked_vesting,
-            )?;
-
-            require!(
-                ctx.accounts.leftover_receiver.key() != Pubkey::default(),
-                PoolError::InvalidLeftoverAddress
-            );
-            require!(
-                minimum_base_supply_without_buffer <= post_migration_token_supply
-                    && post_migration_token_supply <= pre_migration_token_supply
-                    && minimum_base_supply_with_buffer <= pre_migration_token_supply,
-                PoolError::InvalidTokenSupply
-            );
-            (1, pre_migration_token_supply, post_migration_token_supply)
-        } else {
-            (0, 0, 0)
-        };
+    if let Some(TokenSupplyParams {
+        pre_migration_token_supply,
+        post_migration_token_supply,
+    }) = token_supply
+    {
+        require!(
+            leftover_receiver.ne(&Pubkey::default()),
+            PoolError::InvalidLeftoverAddress
+        );
+        validate_fixed_token_supply(
+            &curve_amounts,
+            *sqrt_start_price,
+            curve,
+            locked_vesting,
+            *pre_migration_token_supply,
+            *post_migration_token_supply,
+        )?;
+    }
 
-    let MigratedPoolFee {
-        pool_fee_bps: migrated_pool_fee_bps,
-        collect_fee_mode: migrated_collect_fee_mode,
-        dynamic_fee: migrated_dynamic_fee,
-    } = migrated_pool_fee;
+    config.init(
+        config_parameters,
+        &PoolConfigInitParams {
+            quote_mint: quote_mint.key(),
+            fee_claimer: *fee_claimer,
+            leftover_receiver: *leftover_receiver,
+            quote_token_flag: get_token_program_flags(quote_mint).into(),
+            swap_base_amount: curve_amounts.swap_base_amount,
+            migration_base_threshold: curve_amounts.migration_base_amount,
+            migration_sqrt_price: curve_amounts.migration_sqrt_price,
+            pool_creation_fee_protocol_percent,
+        },
+    );
+    Ok(())
+}
 
+pub fn handle_create_config(
+    ctx: Context<CreateConfigCtx>,
+    config_parameters: ConfigParameters,
+) -> Result<()> {
+    let protocol_config = ctx.accounts.protocol_config.load()?;
     let mut config = ctx.accounts.config.load_init()?;
-    config.init(
-        &ctx.accounts.quote_mint.key(),
+    process_init_config(
+        &mut config,
+        &config_parameters,
+        &ctx.accounts.quote_mint,
         ctx.accounts.fee_claimer.key,
         ctx.accounts.leftover_receiver.key,
-        &pool_fees,
-        creator_trading_fee_percentage,
-        token_update_authority,
-        migration_fee,
+        &protocol_config,
+    )?;
+
+    let ConfigParameters {
+        pool_fees,
         collect_fee_mode,
         migration_option,
         activation_type,
-        token_decimal,
         token_type,
-        get_token_program_flags(&ctx.accounts.quote_mint).into(),
-        partner_locked_lp_percentage,
+        token_decimal,
         partner_lp_percentage,
-        creator_locked_lp_percentage,
+        partner_locked_lp_percentage,
         creator_lp_percentage,
-        &locked_vesting,
-        migration_fee_option,
-        swap_base_amount,
+        creator_locked_lp_percentage,
         migration_quote_threshold,
-        migration_base_amount,
-        sqrt_migration_price,
         sqrt_start_price,
-        fixed_token_supply_flag,
-        pre_migration_token_supply,
-        post_migration_token_supply,
-        migrated_pool_fee_bps,
-        migrated_collect_fee_mode,
-        migrated_dynamic_fee,
-        &curve,
-    );
+        locked_vesting,
+        migration_fee_option,
+        curve,
+        ..
+    } = config_parameters.clone();
 
     emit_cpi!(EvtCreateConfig {
         config: ctx.accounts.config.key(),
@@ -489,13 +709,13 @@ pub fn handle_create_config(
         partner_lp_percentage,
         creator_locked_lp_percentage,
         creator_lp_percentage,
-        swap_base_amount,
+        swap_base_amount: config.swap_base_amount,
         migration_quote_threshold,
-        migration_base_amount,
+        migration_base_amount: config.migration_base_threshold,
         sqrt_start_price,
-        fixed_token_supply_flag,
-        pre_migration_token_supply,
-        post_migration_token_supply,
+        fixed_token_supply_flag: config.fixed_token_supply_flag,
+        pre_migration_token_supply: config.pre_migration_token_supply,
+        post_migration_token_supply: config.post_migration_token_supply,
         locked_vesting,
         migration_fee_option,
         curve
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::CURVE_VARIANTS_PREFIX,
+    params::liquidity_distribution::LiquidityDistributionParameters,
+    state::{CurveVariants, PoolConfig},
+    EvtCreateCurveVariants,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct CurveVariantParameters {
+    pub sqrt_start_price: u128,
+    pub migration_quote_threshold: u64,
+    pub curve: Vec<LiquidityDistributionParameters>,
+}
+
+/// Accounts for partner to publish curve variants that creator can pick at pool creation
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCurveVariantsCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            CURVE_VARIANTS_PREFIX,
+            config.key().as_ref(),
+        ],
+        bump,
+        space = 8 + CurveVariants::INIT_SPACE
+    )]
+    pub curve_variants: AccountLoader<'info, CurveVariants>,
+
+    pub fee_claimer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_curve_variants(
+    ctx: Context<CreateCurveVariantsCtx>,
+    variants: Vec<CurveVariantParameters>,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let mut curve_variants = ctx.accounts.curve_variants.load_init()?;
+    curve_variants.initialize(ctx.accounts.config.key(), &config, &variants)?;
+
+    config.curve_variants_flag = 1;
+
+    emit_cpi!(EvtCreateCurveVariants {
+        config: ctx.accounts.config.key(),
+        curve_variants: ctx.accounts.curve_variants.key(),
+        variants,
+    });
+    Ok(())
+}
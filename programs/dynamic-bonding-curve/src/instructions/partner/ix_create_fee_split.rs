@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    const_pda,
+    constants::seeds::{FEE_SPLIT_PREFIX, FEE_SPLIT_VAULT_PREFIX},
+    state::{FeeSplit, PoolConfig},
+    EvtCreateFeeSplit, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct FeeSplitRecipientParams {
+    pub recipient: Pubkey,
+    pub weight_bps: u16,
+}
+
+/// Accounts for partner to split quote revenue of a config among many recipients
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateFeeSplitCtx<'info> {
+    #[account(mut, has_one = fee_claimer, has_one = quote_mint)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            FEE_SPLIT_PREFIX,
+            config.key().as_ref(),
+        ],
+        bump,
+        space = 8 + FeeSplit::INIT_SPACE
+    )]
+    pub fee_split: AccountLoader<'info, FeeSplit>,
+
+    /// CHECK: pool authority
+    #[account(
+        address = const_pda::pool_authority::ID
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Vault holding quote token of fee split recipients
+    #[account(
+        init,
+        seeds = [
+            FEE_SPLIT_VAULT_PREFIX,
+            fee_split.key().as_ref(),
+        ],
+        token::mint = quote_mint,
+        token::authority = pool_authority,
+        token::token_program = token_quote_program,
+        payer = payer,
+        bump,
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of quote token
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub fee_claimer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token b program
+    pub token_quote_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_fee_split(
+    ctx: Context<CreateFeeSplitCtx>,
+    recipients: Vec<FeeSplitRecipientParams>,
+) -> Result<()> {
+    // partner base trading fees can't be split, so fee split only supports quote fee mode
+    require!(
+        ctx.accounts.config.load()?.is_quote_token_fee_mode(),
+        PoolError::InvalidCollectFeeMode
+    );
+
+    let mut fee_split = ctx.accounts.fee_split.load_init()?;
+    fee_split.initialize(
+        ctx.accounts.config.key(),
+        ctx.accounts.quote_vault.key(),
+        &recipients,
+    )?;
+
+    // partner quote revenue is routed to fee split vault from now on
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.fee_split_flag = 1;
+
+    emit_cpi!(EvtCreateFeeSplit {
+        config: ctx.accounts.config.key(),
+        fee_split: ctx.accounts.fee_split.key(),
+        recipients,
+    });
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::seeds::TOKEN_METADATA_CONFIG_PREFIX,
+    state::{PoolConfig, TokenMetadataConfig},
+    EvtCreateTokenMetadataConfig, PoolError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct TokenMetadataCreatorParameters {
+    /// creator address, default pubkey means pool creator
+    pub address: Pubkey,
+    /// share of royalties in percentage
+    pub share: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct TokenMetadataConfigParameters {
+    /// royalties in basis point
+    pub seller_fee_basis_points: u16,
+    /// whether metadata can be updated, metadata is immutable if token update authority of config is immutable
+    pub is_mutable: bool,
+    /// collection that base tokens belong to, it must be verified by collection authority
+    pub collection: Option<Pubkey>,
+    /// creators with shares summing up to 100, at most 5
+    pub creators: Vec<TokenMetadataCreatorParameters>,
+}
+
+/// Accounts for partner to set metadata options of base tokens created on a config
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateTokenMetadataConfigCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            TOKEN_METADATA_CONFIG_PREFIX,
+            config.key().as_ref(),
+        ],
+        bump,
+        space = 8 + TokenMetadataConfig::INIT_SPACE
+    )]
+    pub token_metadata_config: AccountLoader<'info, TokenMetadataConfig>,
+
+    pub fee_claimer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_token_metadata_config(
+    ctx: Context<CreateTokenMetadataConfigCtx>,
+    params: TokenMetadataConfigParameters,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    // pools already created on the config were launched without these metadata options
+    require!(config.has_no_pool(), PoolError::NotPermitToDoThisAction);
+
+    let mut token_metadata_config = ctx.accounts.token_metadata_config.load_init()?;
+    token_metadata_config.initialize(ctx.accounts.config.key(), &params)?;
+
+    config.pool_fees.token_metadata_config_flag = 1;
+
+    emit_cpi!(EvtCreateTokenMetadataConfig {
+        config: ctx.accounts.config.key(),
+        token_metadata_config: ctx.accounts.token_metadata_config.key(),
+        params,
+    });
+    Ok(())
+}
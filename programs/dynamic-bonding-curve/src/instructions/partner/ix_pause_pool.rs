@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{PoolConfig, VirtualPool},
+    EvtPausePool, EvtResumePool, PoolError,
+};
+
+/// Accounts for partner to pause or resume a pool
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoolPauseStatusCtx<'info> {
+    #[account(has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    #[account(mut, has_one = config)]
+    pub pool: AccountLoader<'info, VirtualPool>,
+
+    pub fee_claimer: Signer<'info>,
+}
+
+pub fn handle_pause_pool(ctx: Context<UpdatePoolPauseStatusCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(!pool.is_paused(), PoolError::PoolIsPaused);
+    pool.set_paused(true);
+
+    emit_cpi!(EvtPausePool {
+        pool: ctx.accounts.pool.key(),
+        config: ctx.accounts.config.key(),
+    });
+
+    Ok(())
+}
+
+pub fn handle_resume_pool(ctx: Context<UpdatePoolPauseStatusCtx>) -> Result<()> {
+    let mut pool = ctx.accounts.pool.load_mut()?;
+    require!(pool.is_paused(), PoolError::PoolIsNotPaused);
+    pool.set_paused(false);
+
+    emit_cpi!(EvtResumePool {
+        pool: ctx.accounts.pool.key(),
+        config: ctx.accounts.config.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::PoolConfig, EvtCancelFeeClaimer, EvtProposeFeeClaimer, EvtUpdateFeeClaimer, PoolError,
+};
+
+/// Accounts for partner to propose a new fee claimer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeFeeClaimerCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub fee_claimer: Signer<'info>,
+
+    /// CHECK: new fee claimer address, can be anything except old fee claimer
+    #[account(
+        constraint = new_fee_claimer.key().ne(fee_claimer.key) @ PoolError::InvalidNewFeeClaimer,
+        constraint = new_fee_claimer.key().ne(&Pubkey::default()) @ PoolError::InvalidNewFeeClaimer,
+    )]
+    pub new_fee_claimer: UncheckedAccount<'info>,
+}
+
+/// Accounts for pending fee claimer to accept the config
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptFeeClaimerCtx<'info> {
+    #[account(
+        mut,
+        constraint = config.load()?.pending_fee_claimer == pending_fee_claimer.key() @ PoolError::InvalidNewFeeClaimer,
+    )]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub pending_fee_claimer: Signer<'info>,
+}
+
+/// Accounts for partner to cancel the pending transfer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelFeeClaimerCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub fee_claimer: Signer<'info>,
+}
+
+pub fn handle_propose_fee_claimer(ctx: Context<ProposeFeeClaimerCtx>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    config.pending_fee_claimer = ctx.accounts.new_fee_claimer.key();
+
+    emit_cpi!(EvtProposeFeeClaimer {
+        config: ctx.accounts.config.key(),
+        fee_claimer: ctx.accounts.fee_claimer.key(),
+        new_fee_claimer: ctx.accounts.new_fee_claimer.key(),
+    });
+    Ok(())
+}
+
+pub fn handle_accept_fee_claimer(ctx: Context<AcceptFeeClaimerCtx>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let fee_claimer = config.fee_claimer;
+    config.fee_claimer = ctx.accounts.pending_fee_claimer.key();
+    config.pending_fee_claimer = Pubkey::default();
+
+    emit_cpi!(EvtUpdateFeeClaimer {
+        config: ctx.accounts.config.key(),
+        fee_claimer,
+        new_fee_claimer: ctx.accounts.pending_fee_claimer.key(),
+    });
+    Ok(())
+}
+
+pub fn handle_cancel_fee_claimer(ctx: Context<CancelFeeClaimerCtx>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    require!(
+        config.has_pending_fee_claimer(),
+        PoolError::NotPermitToDoThisAction
+    );
+    let pending_fee_claimer = config.pending_fee_claimer;
+    config.pending_fee_claimer = Pubkey::default();
+
+    emit_cpi!(EvtCancelFeeClaimer {
+        config: ctx.accounts.config.key(),
+        fee_claimer: ctx.accounts.fee_claimer.key(),
+        new_fee_claimer: pending_fee_claimer,
+    });
+    Ok(())
+}
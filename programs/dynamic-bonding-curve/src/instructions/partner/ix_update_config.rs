@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    const_pda,
+    constants::MAX_CURVE_POINT,
+    process_init_config,
+    safe_math::SafeMath,
+    state::{LiquidityDistributionConfig, PoolConfig, ProtocolConfig},
+    ConfigParameters, EvtUpdateConfig, PoolError,
+};
+
+/// Accounts for partner to update config parameters
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfigCtx<'info> {
+    #[account(mut, has_one = fee_claimer, has_one = quote_mint, has_one = leftover_receiver)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub fee_claimer: Signer<'info>,
+
+    /// CHECK: leftover receiver of config
+    pub leftover_receiver: UncheckedAccount<'info>,
+
+    /// quote mint
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// protocol config, surplus share of config must be approved by admin
+    #[account(address = const_pda::protocol_config::ID)]
+    pub protocol_config: AccountLoader<'info, ProtocolConfig>,
+}
+
+/// All parameters can be updated while no pool is initialized on the config,
+/// otherwise only parameters that are read at pool creation can be updated
+pub fn handle_update_config(
+    ctx: Context<UpdateConfigCtx>,
+    config_parameters: ConfigParameters,
+) -> Result<()> {
+    let protocol_config = ctx.accounts.protocol_config.load()?;
+    let mut config = ctx.accounts.config.load_mut()?;
+    // curve variants are validated against the config
+    require!(
+        !config.has_curve_variants(),
+        PoolError::NotPermitToDoThisAction
+    );
+
+    let mut new_config = Box::new(*config);
+    new_config.curve = [LiquidityDistributionConfig::default(); MAX_CURVE_POINT];
+    process_init_config(
+        &mut new_config,
+        &config_parameters,
+        &ctx.accounts.quote_mint,
+        ctx.accounts.fee_claimer.key,
+        ctx.accounts.leftover_receiver.key,
+        &protocol_config,
+    )?;
+    new_config.pool_count_flag = config.pool_count_flag;
+    new_config.pool_fees.token_metadata_config_flag = config.pool_fees.token_metadata_config_flag;
+    new_config.version = config.version;
+    // fee split only routes quote revenue
+    if config.has_fee_split() {
+        require!(
+            new_config.is_quote_token_fee_mode(),
+            PoolError::InvalidCollectFeeMode
+        );
+    }
+
+    if !config.has_no_pool() {
+        require!(
+            config.is_same_for_existing_pools(&new_config),
+            PoolError::NotPermitToDoThisAction
+        );
+    }
+
+    new_config.version = new_config.version.safe_add(1)?;
+    *config = *new_config;
+
+    emit_cpi!(EvtUpdateConfig {
+        config: ctx.accounts.config.key(),
+        version: config.version,
+        pool_count: config.pool_count,
+        config_parameters,
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::PoolConfig, EvtUpdateLeftoverReceiver, PoolError};
+
+/// Accounts for partner to update leftover receiver
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateLeftoverReceiverCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub fee_claimer: Signer<'info>,
+
+    /// CHECK: new leftover receiver
+    #[account(
+        constraint = new_leftover_receiver.key().ne(&Pubkey::default()) @ PoolError::InvalidLeftoverAddress,
+    )]
+    pub new_leftover_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handle_update_leftover_receiver(ctx: Context<UpdateLeftoverReceiverCtx>) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let leftover_receiver = config.leftover_receiver;
+    config.leftover_receiver = ctx.accounts.new_leftover_receiver.key();
+
+    emit_cpi!(EvtUpdateLeftoverReceiver {
+        config: ctx.accounts.config.key(),
+        leftover_receiver,
+        new_leftover_receiver: ctx.accounts.new_leftover_receiver.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::PoolConfig, EvtUpdatePoolCreatorAuthority};
+
+/// Accounts for partner to restrict pool creation on a config, or open it to anyone
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePoolCreatorAuthorityCtx<'info> {
+    #[account(mut, has_one = fee_claimer)]
+    pub config: AccountLoader<'info, PoolConfig>,
+
+    pub fee_claimer: Signer<'info>,
+
+    /// CHECK: new authority that approves pool creation, default pubkey opens pool creation to anyone
+    pub new_pool_creator_authority: UncheckedAccount<'info>,
+}
+
+pub fn handle_update_pool_creator_authority(
+    ctx: Context<UpdatePoolCreatorAuthorityCtx>,
+) -> Result<()> {
+    let mut config = ctx.accounts.config.load_mut()?;
+    let pool_creator_authority = config.pool_creator_authority;
+    config.pool_creator_authority = ctx.accounts.new_pool_creator_authority.key();
+
+    emit_cpi!(EvtUpdatePoolCreatorAuthority {
+        config: ctx.accounts.config.key(),
+        pool_creator_authority,
+        new_pool_creator_authority: ctx.accounts.new_pool_creator_authority.key(),
+    });
+    Ok(())
+}
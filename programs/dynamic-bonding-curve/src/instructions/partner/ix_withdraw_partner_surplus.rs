@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    const_pda,
-    state::{PoolConfig, VirtualPool},
+    assert_claim_fee_authority, const_pda, route_partner_quote_to_fee_split,
+    state::{
+        get_pool_config, DelegatedClaimFeeOperator, FeeSplit, OperatorScope, PoolConfig,
+        VirtualPool,
+    },
     token::transfer_from_pool,
     EvtPartnerWithdrawSurplus, PoolError,
 };
@@ -18,7 +21,7 @@ pub struct PartnerWithdrawSurplusCtx<'info> {
     )]
     pub pool_authority: UncheckedAccount<'info>,
 
-    #[account(has_one = quote_mint, has_one=fee_claimer)]
+    #[account(has_one = quote_mint)]
     pub config: AccountLoader<'info, PoolConfig>,
 
     #[account(
@@ -39,19 +42,45 @@ pub struct PartnerWithdrawSurplusCtx<'info> {
     /// The mint of quote token
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    pub fee_claimer: Signer<'info>,
+    /// fee claimer or its delegated operator
+    pub signer: Signer<'info>,
+
+    /// delegated operator of fee claimer, only required if signer is not fee claimer
+    pub delegated_operator: Option<AccountLoader<'info, DelegatedClaimFeeOperator>>,
+
+    /// fee split of config, only required if config has fee split
+    #[account(mut)]
+    pub fee_split: Option<AccountLoader<'info, FeeSplit>>,
 
     /// Token b program
     pub token_quote_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_partner_withdraw_surplus(ctx: Context<PartnerWithdrawSurplusCtx>) -> Result<()> {
+pub fn handle_partner_withdraw_surplus<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PartnerWithdrawSurplusCtx<'info>>,
+) -> Result<()> {
     let config = ctx.accounts.config.load()?;
+    // fee split vault is validated when routing surplus
+    let receiver_token_accounts: &[&InterfaceAccount<TokenAccount>] = if config.has_fee_split() {
+        &[]
+    } else {
+        &[&ctx.accounts.token_quote_account]
+    };
+    assert_claim_fee_authority(
+        &config.fee_claimer,
+        OperatorScope::Partner,
+        ctx.accounts.signer.key,
+        &ctx.accounts.delegated_operator,
+        receiver_token_accounts,
+    )?;
+
     let mut pool = ctx.accounts.virtual_pool.load_mut()?;
+    let config = get_pool_config(&config, &pool, ctx.remaining_accounts)?;
 
+    let migration_quote_threshold = pool.get_migration_quote_threshold(&config);
     // Make sure pool has been completed
     require!(
-        pool.is_curve_complete(config.migration_quote_threshold),
+        pool.is_curve_complete(migration_quote_threshold),
         PoolError::NotPermitToDoThisAction
     );
 
@@ -60,9 +89,18 @@ pub fn handle_partner_withdraw_surplus(ctx: Context<PartnerWithdrawSurplusCtx>)
         pool.is_partner_withdraw_surplus == 0,
         PoolError::SurplusHasBeenWithdraw
     );
-    let total_surplus = pool.get_total_surplus(config.migration_quote_threshold)?;
+    let total_surplus = pool.get_total_surplus(migration_quote_threshold)?;
     let partner_surplus_amount = pool.get_partner_surplus(&config, total_surplus)?;
 
+    route_partner_quote_to_fee_split(
+        &ctx.accounts.config.key(),
+        &config,
+        &ctx.accounts.fee_split,
+        &ctx.accounts.quote_mint,
+        &ctx.accounts.token_quote_account,
+        partner_surplus_amount,
+    )?;
+
     transfer_from_pool(
         ctx.accounts.pool_authority.to_account_info(),
         &ctx.accounts.quote_mint,
@@ -6,3 +6,27 @@ pub mod ix_withdraw_partner_surplus;
 pub use ix_withdraw_partner_surplus::*;
 pub mod ix_create_partner_metadata;
 pub use ix_create_partner_metadata::*;
+pub mod ix_pause_pool;
+pub use ix_pause_pool::*;
+pub mod ix_transfer_fee_claimer;
+pub use ix_transfer_fee_claimer::*;
+pub mod ix_update_leftover_receiver;
+pub use ix_update_leftover_receiver::*;
+pub mod ix_batch_claim_partner_trading_fee;
+pub use ix_batch_claim_partner_trading_fee::*;
+pub mod fee_split_route;
+pub use fee_split_route::*;
+pub mod ix_create_fee_split;
+pub use ix_create_fee_split::*;
+pub mod ix_claim_fee_split;
+pub use ix_claim_fee_split::*;
+pub mod ix_update_pool_creator_authority;
+pub use ix_update_pool_creator_authority::*;
+pub mod ix_create_curve_variants;
+pub use ix_create_curve_variants::*;
+pub mod ix_update_config;
+pub use ix_update_config::*;
+pub mod ix_close_config;
+pub use ix_close_config::*;
+pub mod ix_create_token_metadata_config;
+pub use ix_create_token_metadata_config::*;
@@ -31,6 +31,35 @@ pub mod dynamic_bonding_curve {
     use super::*;
 
     /// ADMIN FUNCTIONS ///
+    pub fn initialize_protocol_config(ctx: Context<InitializeProtocolConfigCtx>) -> Result<()> {
+        instructions::handle_initialize_protocol_config(ctx)
+    }
+
+    // propose a new admin, the new admin replaces `replaced_admin` if it is specified
+    pub fn propose_admin(
+        ctx: Context<ProposeAdminCtx>,
+        new_admin: Pubkey,
+        replaced_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::handle_propose_admin(ctx, new_admin, replaced_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+        instructions::handle_accept_admin(ctx)
+    }
+
+    // propose to remove an admin, the removal must be confirmed by another admin
+    pub fn propose_remove_admin(
+        ctx: Context<ProposeRemoveAdminCtx>,
+        removed_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::handle_propose_remove_admin(ctx, removed_admin)
+    }
+
+    pub fn confirm_remove_admin(ctx: Context<ConfirmRemoveAdminCtx>) -> Result<()> {
+        instructions::handle_confirm_remove_admin(ctx)
+    }
+
     pub fn create_claim_fee_operator(ctx: Context<CreateClaimFeeOperatorCtx>) -> Result<()> {
         instructions::handle_create_claim_fee_operator(ctx)
     }
@@ -43,10 +72,40 @@ pub mod dynamic_bonding_curve {
         instructions::handle_claim_protocol_fee(ctx)
     }
 
-    pub fn protocol_withdraw_surplus(ctx: Context<ProtocolWithdrawSurplusCtx>) -> Result<()> {
+    pub fn protocol_withdraw_surplus<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProtocolWithdrawSurplusCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_protocol_withdraw_surplus(ctx)
     }
 
+    // update partner and creator surplus shares that configs can use
+    pub fn update_surplus_share_tiers(
+        ctx: Context<UpdateSurplusShareTiersCtx>,
+        surplus_share_tiers: [u8; constants::MAX_SURPLUS_SHARE_TIER],
+    ) -> Result<()> {
+        instructions::handle_update_surplus_share_tiers(ctx, surplus_share_tiers)
+    }
+
+    // update protocol cut of pool creation fee for configs created afterward
+    pub fn update_pool_creation_fee_protocol_percent(
+        ctx: Context<UpdatePoolCreationFeeProtocolPercentCtx>,
+        percent: u8,
+    ) -> Result<()> {
+        instructions::handle_update_pool_creation_fee_protocol_percent(ctx, percent)
+    }
+
+    pub fn create_global_state(ctx: Context<CreateGlobalStateCtx>) -> Result<()> {
+        instructions::handle_create_global_state(ctx)
+    }
+
+    // block swap, pool creation and config creation program-wide
+    pub fn set_emergency_stop(
+        ctx: Context<SetEmergencyStopCtx>,
+        is_emergency_stopped: bool,
+    ) -> Result<()> {
+        instructions::handle_set_emergency_stop(ctx, is_emergency_stopped)
+    }
+
     /// PARTNER FUNCTIONS ////
     pub fn create_partner_metadata(
         ctx: Context<CreatePartnerMetadataCtx>,
@@ -68,11 +127,95 @@ pub mod dynamic_bonding_curve {
         instructions::handle_claim_trading_fee(ctx, max_amount_a, max_amount_b)
     }
 
+    // claim trading fees of pools in remaining accounts, each pool is passed as (pool, base mint, base vault, quote vault, token a account)
+    pub fn batch_claim_trading_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BatchClaimTradingFeesCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_batch_claim_trading_fee(ctx)
+    }
+
     // withdraw surplus on quote token
-    pub fn partner_withdraw_surplus(ctx: Context<PartnerWithdrawSurplusCtx>) -> Result<()> {
+    pub fn partner_withdraw_surplus<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PartnerWithdrawSurplusCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_partner_withdraw_surplus(ctx)
     }
 
+    // pause trading on a pool, claims and migration still work
+    pub fn pause_pool(ctx: Context<UpdatePoolPauseStatusCtx>) -> Result<()> {
+        instructions::handle_pause_pool(ctx)
+    }
+
+    pub fn resume_pool(ctx: Context<UpdatePoolPauseStatusCtx>) -> Result<()> {
+        instructions::handle_resume_pool(ctx)
+    }
+
+    // transfer config fee claimer in 2 steps, new fee claimer must accept
+    pub fn propose_fee_claimer(ctx: Context<ProposeFeeClaimerCtx>) -> Result<()> {
+        instructions::handle_propose_fee_claimer(ctx)
+    }
+
+    pub fn accept_fee_claimer(ctx: Context<AcceptFeeClaimerCtx>) -> Result<()> {
+        instructions::handle_accept_fee_claimer(ctx)
+    }
+
+    pub fn cancel_fee_claimer(ctx: Context<CancelFeeClaimerCtx>) -> Result<()> {
+        instructions::handle_cancel_fee_claimer(ctx)
+    }
+
+    pub fn update_leftover_receiver(ctx: Context<UpdateLeftoverReceiverCtx>) -> Result<()> {
+        instructions::handle_update_leftover_receiver(ctx)
+    }
+
+    // update config and bump its version, only parameters that are read at pool creation can be updated once the config has pools
+    pub fn update_config(
+        ctx: Context<UpdateConfigCtx>,
+        config_parameters: ConfigParameters,
+    ) -> Result<()> {
+        instructions::handle_update_config(ctx, config_parameters)
+    }
+
+    // close config that no pool references
+    pub fn close_config(ctx: Context<CloseConfigCtx>) -> Result<()> {
+        instructions::handle_close_config(ctx)
+    }
+
+    // only pool creator authority can approve pool creation on the config, default pubkey opens pool creation to anyone
+    pub fn update_pool_creator_authority(
+        ctx: Context<UpdatePoolCreatorAuthorityCtx>,
+    ) -> Result<()> {
+        instructions::handle_update_pool_creator_authority(ctx)
+    }
+
+    // split partner quote revenue of config among recipients, weights can not be changed later
+    pub fn create_fee_split(
+        ctx: Context<CreateFeeSplitCtx>,
+        recipients: Vec<FeeSplitRecipientParams>,
+    ) -> Result<()> {
+        instructions::handle_create_fee_split(ctx, recipients)
+    }
+
+    // fee split recipient claims its share
+    pub fn claim_fee_split(ctx: Context<ClaimFeeSplitCtx>) -> Result<()> {
+        instructions::handle_claim_fee_split(ctx)
+    }
+
+    // publish curve variants that creator can pick at pool creation, variants can not be changed later
+    pub fn create_curve_variants(
+        ctx: Context<CreateCurveVariantsCtx>,
+        variants: Vec<CurveVariantParameters>,
+    ) -> Result<()> {
+        instructions::handle_create_curve_variants(ctx, variants)
+    }
+
+    // set metadata options of base tokens created on the config, options can not be changed later
+    pub fn create_token_metadata_config(
+        ctx: Context<CreateTokenMetadataConfigCtx>,
+        params: TokenMetadataConfigParameters,
+    ) -> Result<()> {
+        instructions::handle_create_token_metadata_config(ctx, params)
+    }
+
     /// POOL CREATOR FUNCTIONS ////
     pub fn initialize_virtual_pool_with_spl_token<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, InitializeVirtualPoolWithSplTokenCtx<'info>>,
@@ -88,6 +231,13 @@ pub mod dynamic_bonding_curve {
         instructions::handle_initialize_virtual_pool_with_token2022(ctx, params)
     }
 
+    pub fn initialize_virtual_pool_with_existing_mint<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVirtualPoolWithExistingMintCtx<'info>>,
+        params: InitializePoolWithExistingMintParameters,
+    ) -> Result<()> {
+        instructions::handle_initialize_virtual_pool_with_existing_mint(ctx, params)
+    }
+
     pub fn create_virtual_pool_metadata(
         ctx: Context<CreateVirtualPoolMetadataCtx>,
         metadata: CreateVirtualPoolMetadataParameters,
@@ -104,23 +254,67 @@ pub mod dynamic_bonding_curve {
     }
 
     // withdraw surplus on quote token
-    pub fn creator_withdraw_surplus(ctx: Context<CreatorWithdrawSurplusCtx>) -> Result<()> {
+    pub fn creator_withdraw_surplus<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CreatorWithdrawSurplusCtx<'info>>,
+    ) -> Result<()> {
         instructions::handle_creator_withdraw_surplus(ctx)
     }
 
-    pub fn transfer_pool_creator<'c: 'info, 'info>(
-        ctx: Context<'_, '_, 'c, 'info, TransferPoolCreatorCtx>,
+    // transfer pool creator in 2 steps, new creator must accept
+    pub fn propose_pool_creator(ctx: Context<ProposePoolCreatorCtx>) -> Result<()> {
+        instructions::handle_propose_pool_creator(ctx)
+    }
+
+    pub fn accept_pool_creator<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AcceptPoolCreatorCtx>,
+    ) -> Result<()> {
+        instructions::handle_accept_pool_creator(ctx)
+    }
+
+    pub fn cancel_pool_creator(ctx: Context<CancelPoolCreatorCtx>) -> Result<()> {
+        instructions::handle_cancel_pool_creator(ctx)
+    }
+
+    // split creator revenue of pool among co-creators, weights can not be changed later
+    pub fn create_creator_fee_split(
+        ctx: Context<CreateCreatorFeeSplitCtx>,
+        co_creators: Vec<FeeSplitRecipientParams>,
     ) -> Result<()> {
-        instructions::handle_transfer_pool_creator(ctx)
+        instructions::handle_create_creator_fee_split(ctx, co_creators)
+    }
+
+    // co-creator claims its share
+    pub fn claim_creator_fee_split(ctx: Context<ClaimCreatorFeeSplitCtx>) -> Result<()> {
+        instructions::handle_claim_creator_fee_split(ctx)
     }
 
     /// BOTH partner and creator FUNCTIONS ///
-    pub fn withdraw_migration_fee(ctx: Context<WithdrawMigrationFeeCtx>, flag: u8) -> Result<()> {
+    // delegate an operator to claim fees on behalf of partner or creator
+    pub fn create_delegated_claim_fee_operator(
+        ctx: Context<CreateDelegatedClaimFeeOperatorCtx>,
+        scope: u8,
+    ) -> Result<()> {
+        instructions::handle_create_delegated_claim_fee_operator(ctx, scope)
+    }
+
+    pub fn close_delegated_claim_fee_operator(
+        ctx: Context<CloseDelegatedClaimFeeOperatorCtx>,
+    ) -> Result<()> {
+        instructions::handle_close_delegated_claim_fee_operator(ctx)
+    }
+
+    pub fn withdraw_migration_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawMigrationFeeCtx<'info>>,
+        flag: u8,
+    ) -> Result<()> {
         instructions::handle_withdraw_migration_fee(ctx, flag)
     }
 
     /// TRADING BOTS FUNCTIONS ////
-    pub fn swap(ctx: Context<SwapCtx>, params: SwapParameters) -> Result<()> {
+    pub fn swap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SwapCtx<'info>>,
+        params: SwapParameters,
+    ) -> Result<()> {
         instructions::handle_swap(ctx, params)
     }
 
@@ -135,6 +329,38 @@ pub mod dynamic_bonding_curve {
         instructions::handle_withdraw_leftover(ctx)
     }
 
+    /// complete curve at the migration deadline if the minimum quote amount is raised
+    pub fn complete_curve_at_deadline<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CompleteCurveAtDeadlineCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_complete_curve_at_deadline(ctx)
+    }
+
+    /// pay surplus to partner, creator and protocol in 1 instruction, parties that already withdrew are skipped
+    pub fn distribute_surplus<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DistributeSurplusCtx<'info>>,
+    ) -> Result<()> {
+        instructions::handle_distribute_surplus(ctx)
+    }
+
+    /// close migrated pool and its empty vaults after every fee, surplus, leftover and migration fee is withdrawn, rent goes to creator
+    pub fn close_virtual_pool(ctx: Context<CloseVirtualPoolCtx>) -> Result<()> {
+        instructions::handle_close_virtual_pool(ctx)
+    }
+
+    /// thaw base token account of a non-transferable pool after migration, anyone can thaw any holder
+    pub fn thaw_base_token_account(ctx: Context<ThawBaseTokenAccountCtx>) -> Result<()> {
+        instructions::handle_thaw_base_token_account(ctx)
+    }
+
+    /// update base token metadata by the update authority of token authority option, creator or partner
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadataCtx>,
+        params: UpdateTokenMetadataParameters,
+    ) -> Result<()> {
+        instructions::handle_update_token_metadata(ctx, params)
+    }
+
     /// migrate damm v1
     pub fn migration_meteora_damm_create_metadata<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, MigrationMeteoraDammCreateMetadataCtx<'info>>,
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use ruint::aliases::U256;
 use static_assertions::const_assert_eq;
@@ -7,20 +8,23 @@ use crate::{
     base_fee::{get_base_fee_handler, FeeRateLimiter},
     constants::{
         fee::{FEE_DENOMINATOR, MAX_FEE_NUMERATOR},
-        MAX_CURVE_POINT_CONFIG, MAX_SQRT_PRICE, MAX_SWALLOW_PERCENTAGE, SWAP_BUFFER_PERCENTAGE,
+        MAX_CURVE_POINT, MAX_SQRT_PRICE, MAX_SWALLOW_PERCENTAGE, PARTNER_AND_CREATOR_SURPLUS_SHARE,
+        SWAP_BUFFER_PERCENTAGE,
     },
     params::{
-        fee_parameters::PoolFeeParameters,
         liquidity_distribution::{get_base_token_for_swap, LiquidityDistributionParameters},
         swap::TradeDirection,
     },
     safe_math::SafeMath,
     u128x128_math::Rounding,
     utils_math::{safe_mul_div_cast_u128, safe_mul_div_cast_u64},
-    LockedVestingParams, MigrationFee, PoolError,
+    ConfigParameters, LockedVestingParams, PoolError,
 };
 
-use super::fee::{FeeOnAmountResult, VolatilityTracker};
+use super::{
+    fee::{FeeOnAmountResult, VolatilityTracker},
+    CollectFeeMode, CurveVariant,
+};
 
 /// base fee mode
 #[repr(u8)]
@@ -49,8 +53,22 @@ pub enum BaseFeeMode {
 pub struct PoolFeesConfig {
     pub base_fee: BaseFeeConfig,
     pub dynamic_fee: DynamicFeeConfig,
-    pub padding_0: [u64; 5],
-    pub padding_1: [u8; 6],
+    /// fee paid by pool creator when a pool is initialized on the config
+    pub pool_creation_fee: u64,
+    /// max transfer fee of token 2022 base mint per transfer
+    pub base_max_transfer_fee: u64,
+    /// padding for future use
+    pub padding_0: [u64; 3],
+    /// transfer fee of token 2022 base mint in bps, 0 means base mint has no transfer fee
+    pub base_transfer_fee_bps: u16,
+    /// owner of withheld transfer fees of base mint, 0: creator, 1: partner
+    pub base_transfer_fee_authority: u8,
+    /// flag to indicate whether metadata of base tokens follows token metadata config account, kept here as the last free byte of config
+    pub token_metadata_config_flag: u8,
+    /// token of pool creation fee, 0: SOL, 1: quote token
+    pub pool_creation_fee_token: u8,
+    /// protocol cut of pool creation fee in percentage
+    pub pool_creation_fee_protocol_percent: u8,
     pub protocol_fee_percent: u8,
     pub referral_fee_percent: u8,
 }
@@ -352,6 +370,47 @@ pub enum TokenType {
     Token2022,
 }
 
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum PoolCreationFeeToken {
+    Sol,
+    QuoteToken,
+}
+
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum BaseTransferFeeAuthority {
+    Creator,
+    Partner,
+}
+
+impl BaseTransferFeeAuthority {
+    pub fn get_withdraw_withheld_authority(&self, creator: Pubkey, partner: Pubkey) -> Pubkey {
+        match *self {
+            BaseTransferFeeAuthority::Creator => creator,
+            BaseTransferFeeAuthority::Partner => partner,
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(
     Clone,
@@ -447,8 +506,20 @@ pub struct PoolConfig {
     pub migration_fee_percentage: u8,
     /// creator migration fee percentage
     pub creator_migration_fee_percentage: u8,
+    /// flag to indicate whether partner quote revenue is split among recipients of fee split account
+    pub fee_split_flag: u8,
+    /// partner and creator share of surplus in percentage, 0 means default share and surplus is split by creator trading fee percentage
+    pub partner_and_creator_surplus_share: u8,
+    /// creator percentage of partner and creator surplus
+    pub creator_surplus_percentage: u8,
     /// padding 0
-    pub _padding_0: [u8; 7],
+    pub _padding_0: u8,
+    /// flag to indicate whether creator can pick a curve variant of curve variants account at pool creation
+    pub curve_variants_flag: u8,
+    /// flag to indicate whether pools of config are counted since config creation, pool count of configs created before is unknown
+    pub pool_count_flag: u8,
+    /// flag to indicate whether base token of pools can only move through swaps until migration
+    pub non_transferable_flag: u8,
     /// swap base amount
     pub swap_base_amount: u64,
     /// migration quote threshold (in quote token)
@@ -469,16 +540,28 @@ pub struct PoolConfig {
     pub migrated_dynamic_fee: u8,
     /// migrated pool fee in bps
     pub migrated_pool_fee_bps: u16,
-    /// padding 1
-    pub _padding_1: [u8; 12],
-    /// padding 2
-    pub _padding_2: u128,
+    /// max price change in bps within the circuit breaker window, 0 means circuit breaker is disabled
+    pub circuit_breaker_max_price_change_bps: u16,
+    /// circuit breaker window in seconds, 0 means the dynamic fee references are used
+    pub circuit_breaker_window: u16,
+    /// number of pools that are initialized on config
+    pub pool_count: u64,
+    /// minimum quote amount that the pool must raise to be completed at the migration deadline
+    pub min_migration_quote_threshold: u64,
+    /// duration from the activation point (slot or timestamp) to the migration deadline, 0 means no deadline
+    pub migration_deadline_duration: u64,
     /// minimum price
     pub sqrt_start_price: u128,
-    /// curve, only use 20 point firstly, we can extend that latter
+    /// curve
     // each distribution will include curve[i].sqrt_price + curve[i+1].sqrt_price + curve[i+1].liquidity
     // for the first: sqrt_start_price + curve[0].sqrt_price + curve[0].liquidity
-    pub curve: [LiquidityDistributionConfig; MAX_CURVE_POINT_CONFIG],
+    pub curve: [LiquidityDistributionConfig; MAX_CURVE_POINT],
+    /// pending fee claimer, must accept to become the fee claimer
+    pub pending_fee_claimer: Pubkey,
+    /// authority that must approve pool creation, default pubkey means anyone can create pools on the config
+    pub pool_creator_authority: Pubkey,
+    /// padding, taken from curve points above MAX_CURVE_POINT that were never written
+    pub _padding: [u64; 8],
 }
 
 const_assert_eq!(PoolConfig::INIT_SPACE, 1040);
@@ -490,6 +573,18 @@ pub struct LiquidityDistributionConfig {
     pub liquidity: u128,
 }
 
+/// values of a config that are not taken from config parameters as is
+pub struct PoolConfigInitParams {
+    pub quote_mint: Pubkey,
+    pub fee_claimer: Pubkey,
+    pub leftover_receiver: Pubkey,
+    pub quote_token_flag: u8,
+    pub swap_base_amount: u64,
+    pub migration_base_threshold: u64,
+    pub migration_sqrt_price: u128,
+    pub pool_creation_fee_protocol_percent: u8,
+}
+
 impl LiquidityDistributionConfig {
     pub fn to_liquidity_distribution_parameters(&self) -> LiquidityDistributionParameters {
         LiquidityDistributionParameters {
@@ -502,73 +597,101 @@ impl LiquidityDistributionConfig {
 impl PoolConfig {
     pub fn init(
         &mut self,
-        quote_mint: &Pubkey,
-        fee_claimer: &Pubkey,
-        leftover_receiver: &Pubkey,
-        pool_fees: &PoolFeeParameters,
-        creator_trading_fee_percentage: u8,
-        token_update_authority: u8,
-        migration_fee: MigrationFee,
-        collect_fee_mode: u8,
-        migration_option: u8,
-        activation_type: u8,
-        token_decimal: u8,
-        token_type: u8,
-        quote_token_flag: u8,
-        partner_locked_lp_percentage: u8,
-        partner_lp_percentage: u8,
-        creator_locked_lp_percentage: u8,
-        creator_lp_percentage: u8,
-        locked_vesting_params: &LockedVestingParams,
-        migration_fee_option: u8,
-        swap_base_amount: u64,
-        migration_quote_threshold: u64,
-        migration_base_threshold: u64,
-        migration_sqrt_price: u128,
-        sqrt_start_price: u128,
-        fixed_token_supply_flag: u8,
-        pre_migration_token_supply: u64,
-        post_migration_token_supply: u64,
-        migrated_pool_fee_bps: u16,
-        migrated_collect_fee_mode: u8,
-        migrated_dynamic_fee: u8,
-        curve: &Vec<LiquidityDistributionParameters>,
+        config_parameters: &ConfigParameters,
+        init_params: &PoolConfigInitParams,
     ) {
+        let ConfigParameters {
+            pool_fees,
+            collect_fee_mode,
+            migration_option,
+            activation_type,
+            token_type,
+            token_decimal,
+            partner_lp_percentage,
+            partner_locked_lp_percentage,
+            creator_lp_percentage,
+            creator_locked_lp_percentage,
+            migration_quote_threshold,
+            sqrt_start_price,
+            locked_vesting,
+            migration_fee_option,
+            token_supply,
+            creator_trading_fee_percentage,
+            token_update_authority,
+            migration_fee,
+            migrated_pool_fee,
+            min_migration_quote_threshold,
+            migration_deadline_duration,
+            circuit_breaker_max_price_change_bps,
+            circuit_breaker_window,
+            partner_and_creator_surplus_share,
+            creator_surplus_percentage,
+            pool_creation_fee_token,
+            pool_creation_fee,
+            base_transfer_fee_bps,
+            base_transfer_fee_authority,
+            non_transferable_until_migration,
+            base_max_transfer_fee,
+            curve,
+            ..
+        } = config_parameters;
+
         self.version = 0;
-        self.quote_mint = *quote_mint;
-        self.fee_claimer = *fee_claimer;
-        self.leftover_receiver = *leftover_receiver;
+        self.pool_count_flag = 1;
+        self.quote_mint = init_params.quote_mint;
+        self.fee_claimer = init_params.fee_claimer;
+        self.leftover_receiver = init_params.leftover_receiver;
         self.pool_fees = pool_fees.to_pool_fees_config();
-        self.creator_trading_fee_percentage = creator_trading_fee_percentage;
-        self.token_update_authority = token_update_authority;
+        self.pool_fees.pool_creation_fee = *pool_creation_fee;
+        self.pool_fees.pool_creation_fee_token = *pool_creation_fee_token;
+        self.pool_fees.pool_creation_fee_protocol_percent =
+            init_params.pool_creation_fee_protocol_percent;
+        self.pool_fees.base_transfer_fee_bps = *base_transfer_fee_bps;
+        self.pool_fees.base_max_transfer_fee = *base_max_transfer_fee;
+        self.pool_fees.base_transfer_fee_authority = *base_transfer_fee_authority;
+        self.non_transferable_flag = *non_transferable_until_migration;
+        self.creator_trading_fee_percentage = *creator_trading_fee_percentage;
+        self.token_update_authority = *token_update_authority;
         self.migration_fee_percentage = migration_fee.fee_percentage;
         self.creator_migration_fee_percentage = migration_fee.creator_fee_percentage;
-        self.collect_fee_mode = collect_fee_mode;
-        self.migration_option = migration_option;
-        self.activation_type = activation_type;
-        self.token_decimal = token_decimal;
-        self.swap_base_amount = swap_base_amount;
-        self.migration_quote_threshold = migration_quote_threshold;
-        self.migration_base_threshold = migration_base_threshold;
-        self.migration_sqrt_price = migration_sqrt_price;
-        self.sqrt_start_price = sqrt_start_price;
-        self.token_type = token_type;
-        self.quote_token_flag = quote_token_flag;
-
-        self.partner_lp_percentage = partner_lp_percentage;
-        self.partner_locked_lp_percentage = partner_locked_lp_percentage;
-
-        self.creator_lp_percentage = creator_lp_percentage;
-        self.creator_locked_lp_percentage = creator_locked_lp_percentage;
-
-        self.locked_vesting_config = locked_vesting_params.to_locked_vesting_config();
-        self.migration_fee_option = migration_fee_option;
-        self.fixed_token_supply_flag = fixed_token_supply_flag;
-        self.pre_migration_token_supply = pre_migration_token_supply;
-        self.post_migration_token_supply = post_migration_token_supply;
-        self.migrated_pool_fee_bps = migrated_pool_fee_bps;
-        self.migrated_collect_fee_mode = migrated_collect_fee_mode;
-        self.migrated_dynamic_fee = migrated_dynamic_fee;
+        self.collect_fee_mode = *collect_fee_mode;
+        self.migration_option = *migration_option;
+        self.activation_type = *activation_type;
+        self.token_decimal = *token_decimal;
+        self.swap_base_amount = init_params.swap_base_amount;
+        self.migration_quote_threshold = *migration_quote_threshold;
+        self.migration_base_threshold = init_params.migration_base_threshold;
+        self.migration_sqrt_price = init_params.migration_sqrt_price;
+        self.sqrt_start_price = *sqrt_start_price;
+        self.token_type = *token_type;
+        self.quote_token_flag = init_params.quote_token_flag;
+
+        self.partner_lp_percentage = *partner_lp_percentage;
+        self.partner_locked_lp_percentage = *partner_locked_lp_percentage;
+
+        self.creator_lp_percentage = *creator_lp_percentage;
+        self.creator_locked_lp_percentage = *creator_locked_lp_percentage;
+
+        self.locked_vesting_config = locked_vesting.to_locked_vesting_config();
+        self.migration_fee_option = *migration_fee_option;
+        if let Some(token_supply) = token_supply {
+            self.fixed_token_supply_flag = 1;
+            self.pre_migration_token_supply = token_supply.pre_migration_token_supply;
+            self.post_migration_token_supply = token_supply.post_migration_token_supply;
+        } else {
+            self.fixed_token_supply_flag = 0;
+            self.pre_migration_token_supply = 0;
+            self.post_migration_token_supply = 0;
+        }
+        self.migrated_pool_fee_bps = migrated_pool_fee.pool_fee_bps;
+        self.migrated_collect_fee_mode = migrated_pool_fee.collect_fee_mode;
+        self.migrated_dynamic_fee = migrated_pool_fee.dynamic_fee;
+        self.min_migration_quote_threshold = *min_migration_quote_threshold;
+        self.migration_deadline_duration = *migration_deadline_duration;
+        self.circuit_breaker_max_price_change_bps = *circuit_breaker_max_price_change_bps;
+        self.circuit_breaker_window = *circuit_breaker_window;
+        self.partner_and_creator_surplus_share = *partner_and_creator_surplus_share;
+        self.creator_surplus_percentage = *creator_surplus_percentage;
 
         for i in 0..curve.len() {
             self.curve[i] = curve[i].to_liquidity_distribution_config();
@@ -581,9 +704,12 @@ impl PoolConfig {
         Ok(token_authority)
     }
 
-    pub fn get_migration_quote_amount_for_config(&self) -> Result<MigrationAmount> {
+    pub fn get_migration_quote_amount_for_config(
+        &self,
+        migration_quote_threshold: u64,
+    ) -> Result<MigrationAmount> {
         PoolConfig::get_migration_quote_amount(
-            self.migration_quote_threshold,
+            migration_quote_threshold,
             self.migration_fee_percentage,
         )
     }
@@ -601,8 +727,12 @@ impl PoolConfig {
         Ok(MigrationAmount { quote_amount, fee })
     }
 
-    pub fn get_migration_fee_distribution(&self) -> Result<MigrationFeeDistribution> {
-        let MigrationAmount { fee, .. } = self.get_migration_quote_amount_for_config()?;
+    pub fn get_migration_fee_distribution(
+        &self,
+        migration_quote_threshold: u64,
+    ) -> Result<MigrationFeeDistribution> {
+        let MigrationAmount { fee, .. } =
+            self.get_migration_quote_amount_for_config(migration_quote_threshold)?;
 
         let creator_migration_fee = safe_mul_div_cast_u64(
             fee,
@@ -654,7 +784,7 @@ impl PoolConfig {
             Ok(self.pre_migration_token_supply)
         } else {
             let mut curve = vec![];
-            for i in 0..MAX_CURVE_POINT_CONFIG {
+            for i in 0..MAX_CURVE_POINT {
                 if self.curve[i].liquidity == 0 {
                     break;
                 }
@@ -673,7 +803,7 @@ impl PoolConfig {
         }
     }
 
-    fn get_max_burnable_amount_post_migration(&self) -> Result<u64> {
+    pub fn get_max_burnable_amount_post_migration(&self) -> Result<u64> {
         if self.is_fixed_token_supply() {
             Ok(self
                 .pre_migration_token_supply
@@ -683,16 +813,132 @@ impl PoolConfig {
         }
     }
 
-    /// leftover is extra base token in base vault after curve is completed
-    pub fn get_burnable_amount_post_migration(&self, leftover: u64) -> Result<u64> {
-        let max_burnable_amount = self.get_max_burnable_amount_post_migration()?;
-        Ok(max_burnable_amount.min(leftover))
-    }
-
     pub fn is_fixed_token_supply(&self) -> bool {
         self.fixed_token_supply_flag == 1
     }
 
+    pub fn is_non_transferable(&self) -> bool {
+        self.non_transferable_flag == 1
+    }
+
+    pub fn has_token_metadata_config(&self) -> bool {
+        self.pool_fees.token_metadata_config_flag == 1
+    }
+
+    pub fn has_pool_creator_authority(&self) -> bool {
+        self.pool_creator_authority != Pubkey::default()
+    }
+
+    pub fn increase_pool_count(&mut self) -> Result<()> {
+        self.pool_count = self.pool_count.safe_add(1)?;
+        Ok(())
+    }
+
+    pub fn decrease_pool_count(&mut self) -> Result<()> {
+        // pools created before pool counting are not counted
+        self.pool_count = if self.pool_count_flag == 1 {
+            self.pool_count.safe_sub(1)?
+        } else {
+            self.pool_count.saturating_sub(1)
+        };
+        Ok(())
+    }
+
+    /// pool count is only known for configs created with pool counting
+    pub fn has_no_pool(&self) -> bool {
+        self.pool_count_flag == 1 && self.pool_count == 0
+    }
+
+    /// configs only differ in fields that are read at pool creation, so existing pools are not affected
+    pub fn is_same_for_existing_pools(&self, other: &PoolConfig) -> bool {
+        let mut other = Box::new(*other);
+        other.token_decimal = self.token_decimal;
+        other.pool_fees.pool_creation_fee = self.pool_fees.pool_creation_fee;
+        other.pool_fees.pool_creation_fee_token = self.pool_fees.pool_creation_fee_token;
+        other.pool_fees.pool_creation_fee_protocol_percent =
+            self.pool_fees.pool_creation_fee_protocol_percent;
+        other.pool_fees.base_transfer_fee_bps = self.pool_fees.base_transfer_fee_bps;
+        other.pool_fees.base_max_transfer_fee = self.pool_fees.base_max_transfer_fee;
+        other.pool_fees.base_transfer_fee_authority = self.pool_fees.base_transfer_fee_authority;
+        other.non_transferable_flag = self.non_transferable_flag;
+        bytemuck::bytes_of(self) == bytemuck::bytes_of(other.as_ref())
+    }
+
+    pub fn has_base_transfer_fee(&self) -> bool {
+        self.pool_fees.base_transfer_fee_bps != 0
+    }
+
+    /// transfer fee of base mint, immutable after the mint is created
+    pub fn get_base_transfer_fee(&self) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: self.pool_fees.base_max_transfer_fee.into(),
+            transfer_fee_basis_points: self.pool_fees.base_transfer_fee_bps.into(),
+        }
+    }
+
+    pub fn get_base_transfer_fee_authority(&self) -> Result<BaseTransferFeeAuthority> {
+        BaseTransferFeeAuthority::try_from(self.pool_fees.base_transfer_fee_authority)
+            .map_err(|_| PoolError::InvalidBaseTransferFee.into())
+    }
+
+    /// extra base token minted to the base vault to pay transfer fees when base token leaves the vault at migration,
+    /// migration base amount is deposited in 2 positions so 1 more max fee is added, the unused amount is burned at migration
+    pub fn get_base_transfer_fee_buffer(&self) -> Result<u64> {
+        if !self.has_base_transfer_fee() || self.is_fixed_token_supply() {
+            return Ok(0);
+        }
+        let transfer_fee = self.get_base_transfer_fee();
+        let locked_vesting_amount = self
+            .locked_vesting_config
+            .to_locked_vesting_params()
+            .get_total_amount()?;
+        let migration_fee = transfer_fee
+            .calculate_fee(self.migration_base_threshold)
+            .ok_or(PoolError::MathOverflow)?;
+        let locked_vesting_fee = transfer_fee
+            .calculate_fee(locked_vesting_amount)
+            .ok_or(PoolError::MathOverflow)?;
+        Ok(migration_fee
+            .safe_add(locked_vesting_fee)?
+            .safe_add(self.pool_fees.base_max_transfer_fee)?)
+    }
+
+    pub fn has_curve_variants(&self) -> bool {
+        self.curve_variants_flag == 1
+    }
+
+    /// overwrite curve of config with the curve variant, the copy is used by swap and migration math of the pool
+    pub fn apply_curve_variant(&mut self, variant: &CurveVariant) {
+        self.sqrt_start_price = variant.sqrt_start_price;
+        self.migration_sqrt_price = variant.migration_sqrt_price;
+        self.swap_base_amount = variant.swap_base_amount;
+        self.migration_quote_threshold = variant.migration_quote_threshold;
+        self.migration_base_threshold = variant.migration_base_threshold;
+        self.curve = variant.curve;
+    }
+
+    /// trading fees are only collected in quote token, so partner and creator have no base trading fee
+    pub fn is_quote_token_fee_mode(&self) -> bool {
+        self.collect_fee_mode == u8::from(CollectFeeMode::QuoteToken)
+    }
+
+    pub fn has_pending_fee_claimer(&self) -> bool {
+        self.pending_fee_claimer != Pubkey::default()
+    }
+
+    pub fn has_fee_split(&self) -> bool {
+        self.fee_split_flag == 1
+    }
+
+    pub fn has_migration_deadline(&self) -> bool {
+        self.migration_deadline_duration != 0
+    }
+
+    pub fn is_circuit_breaker_enabled(&self) -> bool {
+        self.circuit_breaker_max_price_change_bps != 0
+    }
+
     pub fn get_lp_distribution(&self, lp_amount: u64) -> Result<LiquidityDistributionU64> {
         let partner_locked_lp = safe_mul_div_cast_u64(
             lp_amount,
@@ -791,6 +1037,59 @@ impl PoolConfig {
             creator_fee,
         })
     }
+
+    pub fn get_pool_creation_fee_token(&self) -> Result<PoolCreationFeeToken> {
+        PoolCreationFeeToken::try_from(self.pool_fees.pool_creation_fee_token)
+            .map_err(|_| PoolError::TypeCastFailed.into())
+    }
+
+    pub fn split_pool_creation_fee(&self) -> Result<PoolCreationFeeSplit> {
+        let protocol_fee = safe_mul_div_cast_u64(
+            self.pool_fees.pool_creation_fee,
+            self.pool_fees.pool_creation_fee_protocol_percent.into(),
+            100,
+            Rounding::Down,
+        )?;
+        let partner_fee = self.pool_fees.pool_creation_fee.safe_sub(protocol_fee)?;
+        Ok(PoolCreationFeeSplit {
+            partner_fee,
+            protocol_fee,
+        })
+    }
+
+    pub fn get_partner_and_creator_surplus_share(&self) -> u8 {
+        if self.partner_and_creator_surplus_share == 0 {
+            PARTNER_AND_CREATOR_SURPLUS_SHARE
+        } else {
+            self.partner_and_creator_surplus_share
+        }
+    }
+
+    pub fn split_partner_and_creator_surplus(
+        &self,
+        surplus: u64,
+    ) -> Result<PartnerAndCreatorSplitFee> {
+        // configs without surplus share split surplus like trading fee
+        if self.partner_and_creator_surplus_share == 0 {
+            return self.split_partner_and_creator_fee(surplus);
+        }
+        let creator_fee = safe_mul_div_cast_u64(
+            surplus,
+            self.creator_surplus_percentage.into(),
+            100,
+            Rounding::Down,
+        )?;
+        let partner_fee = surplus.safe_sub(creator_fee)?;
+        Ok(PartnerAndCreatorSplitFee {
+            partner_fee,
+            creator_fee,
+        })
+    }
+}
+
+pub struct PoolCreationFeeSplit {
+    pub partner_fee: u64,
+    pub protocol_fee: u64,
 }
 
 pub struct PartnerAndCreatorSplitFee {
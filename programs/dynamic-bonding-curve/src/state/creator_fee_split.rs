@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{BASIS_POINT_MAX, MAX_FEE_SPLIT_RECIPIENT},
+    safe_math::SafeMath,
+    state::validate_fee_split_recipients,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+    FeeSplitRecipientParams, PoolError,
+};
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct CoCreator {
+    /// co-creator
+    pub co_creator: Pubkey,
+    /// share of creator revenue in basis point
+    pub weight_bps: u16,
+    /// padding 0
+    pub _padding_0: [u8; 6],
+    /// amount of base token that co-creator can claim
+    pub pending_base_amount: u64,
+    /// amount of quote token that co-creator can claim
+    pub pending_quote_amount: u64,
+    /// total amount of base token that co-creator has claimed
+    pub total_claimed_base_amount: u64,
+    /// total amount of quote token that co-creator has claimed
+    pub total_claimed_quote_amount: u64,
+}
+
+const_assert_eq!(CoCreator::INIT_SPACE, 72);
+
+impl CoCreator {
+    pub fn is_initialized(&self) -> bool {
+        self.weight_bps != 0
+    }
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Split creator revenue (trading fee and surplus) of a pool among many co-creators
+pub struct CreatorFeeSplit {
+    /// pool
+    pub pool: Pubkey,
+    /// vault holding base token that is not claimed by co-creators
+    pub base_vault: Pubkey,
+    /// vault holding quote token that is not claimed by co-creators
+    pub quote_vault: Pubkey,
+    /// co-creators
+    pub co_creators: [CoCreator; MAX_FEE_SPLIT_RECIPIENT],
+    /// Reserve
+    pub _padding: [u8; 64],
+}
+
+const_assert_eq!(CreatorFeeSplit::INIT_SPACE, 736);
+
+impl CreatorFeeSplit {
+    pub fn initialize(
+        &mut self,
+        pool: Pubkey,
+        base_vault: Pubkey,
+        quote_vault: Pubkey,
+        co_creators: &[FeeSplitRecipientParams],
+    ) -> Result<()> {
+        validate_fee_split_recipients(co_creators)?;
+        self.pool = pool;
+        self.base_vault = base_vault;
+        self.quote_vault = quote_vault;
+        for (slot, params) in self.co_creators.iter_mut().zip(co_creators.iter()) {
+            slot.co_creator = params.recipient;
+            slot.weight_bps = params.weight_bps;
+        }
+        Ok(())
+    }
+
+    /// credit co-creators by weight, rounding dust goes to the last co-creator
+    pub fn distribute(&mut self, base_amount: u64, quote_amount: u64) -> Result<()> {
+        if base_amount == 0 && quote_amount == 0 {
+            return Ok(());
+        }
+        let last_index = self
+            .co_creators
+            .iter()
+            .rposition(|c| c.is_initialized())
+            .ok_or(PoolError::InvalidFeeSplitRecipients)?;
+
+        let mut remaining_base_amount = base_amount;
+        let mut remaining_quote_amount = quote_amount;
+        for co_creator in self.co_creators[..last_index].iter_mut() {
+            if !co_creator.is_initialized() {
+                continue;
+            }
+            let base_share = safe_mul_div_cast_u64(
+                base_amount,
+                co_creator.weight_bps.into(),
+                BASIS_POINT_MAX,
+                Rounding::Down,
+            )?;
+            let quote_share = safe_mul_div_cast_u64(
+                quote_amount,
+                co_creator.weight_bps.into(),
+                BASIS_POINT_MAX,
+                Rounding::Down,
+            )?;
+            co_creator.pending_base_amount = co_creator.pending_base_amount.safe_add(base_share)?;
+            co_creator.pending_quote_amount =
+                co_creator.pending_quote_amount.safe_add(quote_share)?;
+            remaining_base_amount = remaining_base_amount.safe_sub(base_share)?;
+            remaining_quote_amount = remaining_quote_amount.safe_sub(quote_share)?;
+        }
+
+        let last_co_creator = &mut self.co_creators[last_index];
+        last_co_creator.pending_base_amount = last_co_creator
+            .pending_base_amount
+            .safe_add(remaining_base_amount)?;
+        last_co_creator.pending_quote_amount = last_co_creator
+            .pending_quote_amount
+            .safe_add(remaining_quote_amount)?;
+        Ok(())
+    }
+
+    /// return pending base and quote amount of the co-creator and reset them
+    pub fn claim(&mut self, co_creator: &Pubkey) -> Result<(u64, u64)> {
+        let co_creator = self
+            .co_creators
+            .iter_mut()
+            .find(|c| c.is_initialized() && c.co_creator.eq(co_creator))
+            .ok_or(PoolError::NotPermitToDoThisAction)?;
+
+        let base_amount = co_creator.pending_base_amount;
+        let quote_amount = co_creator.pending_quote_amount;
+        co_creator.pending_base_amount = 0;
+        co_creator.pending_quote_amount = 0;
+        co_creator.total_claimed_base_amount =
+            co_creator.total_claimed_base_amount.safe_add(base_amount)?;
+        co_creator.total_claimed_quote_amount = co_creator
+            .total_claimed_quote_amount
+            .safe_add(quote_amount)?;
+        Ok((base_amount, quote_amount))
+    }
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{MAX_CURVE_POINT, MAX_CURVE_VARIANT},
+    get_curve_amounts,
+    state::{LiquidityDistributionConfig, PoolConfig, VirtualPool},
+    validate_curve, validate_fixed_token_supply, CurveAmounts, CurveVariantParameters, PoolError,
+};
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct CurveVariant {
+    /// minimum price
+    pub sqrt_start_price: u128,
+    /// migration sqrt price
+    pub migration_sqrt_price: u128,
+    /// swap base amount
+    pub swap_base_amount: u64,
+    /// migration quote threshold (in quote token)
+    pub migration_quote_threshold: u64,
+    /// migration base threshold (in base token)
+    pub migration_base_threshold: u64,
+    /// padding 0
+    pub _padding_0: u64,
+    /// curve
+    pub curve: [LiquidityDistributionConfig; MAX_CURVE_POINT],
+}
+
+const_assert_eq!(CurveVariant::INIT_SPACE, 576);
+
+impl CurveVariant {
+    pub fn is_initialized(&self) -> bool {
+        self.sqrt_start_price != 0
+    }
+
+    /// validate curve variant against config, the same way as curve of config is validated
+    pub fn init(&mut self, config: &PoolConfig, params: &CurveVariantParameters) -> Result<()> {
+        let CurveVariantParameters {
+            sqrt_start_price,
+            migration_quote_threshold,
+            curve,
+        } = params;
+
+        validate_curve(*sqrt_start_price, curve)?;
+        require!(
+            *migration_quote_threshold > 0
+                && *migration_quote_threshold >= config.min_migration_quote_threshold,
+            PoolError::InvalidQuoteThreshold
+        );
+
+        let curve_amounts = get_curve_amounts(
+            *migration_quote_threshold,
+            *sqrt_start_price,
+            curve,
+            config.migration_fee_percentage,
+            config.migration_option,
+        )?;
+        // fixed token supply of config must work for every curve variant
+        if config.is_fixed_token_supply() {
+            validate_fixed_token_supply(
+                &curve_amounts,
+                *sqrt_start_price,
+                curve,
+                &config.locked_vesting_config.to_locked_vesting_params(),
+                config.pre_migration_token_supply,
+                config.post_migration_token_supply,
+            )?;
+        }
+
+        let CurveAmounts {
+            migration_sqrt_price,
+            swap_base_amount,
+            migration_base_amount,
+        } = curve_amounts;
+        self.sqrt_start_price = *sqrt_start_price;
+        self.migration_sqrt_price = migration_sqrt_price;
+        self.swap_base_amount = swap_base_amount;
+        self.migration_quote_threshold = *migration_quote_threshold;
+        self.migration_base_threshold = migration_base_amount;
+        for (slot, point) in self.curve.iter_mut().zip(curve.iter()) {
+            *slot = point.to_liquidity_distribution_config();
+        }
+        Ok(())
+    }
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Curve variants of a config, creator picks one of them or curve of config at pool creation
+pub struct CurveVariants {
+    /// config
+    pub config: Pubkey,
+    /// curve variants
+    pub variants: [CurveVariant; MAX_CURVE_VARIANT],
+    /// Reserve
+    pub _padding: [u8; 64],
+}
+
+const_assert_eq!(CurveVariants::INIT_SPACE, 2400);
+
+impl CurveVariants {
+    pub fn initialize(
+        &mut self,
+        config_key: Pubkey,
+        config: &PoolConfig,
+        variants: &[CurveVariantParameters],
+    ) -> Result<()> {
+        require!(
+            !variants.is_empty() && variants.len() <= MAX_CURVE_VARIANT,
+            PoolError::InvalidCurveVariant
+        );
+        self.config = config_key;
+        for (slot, params) in self.variants.iter_mut().zip(variants.iter()) {
+            slot.init(config, params)?;
+        }
+        Ok(())
+    }
+
+    /// curve variant by 1-based index
+    pub fn get_variant(&self, curve_variant: u8) -> Result<&CurveVariant> {
+        let index = usize::from(curve_variant)
+            .checked_sub(1)
+            .ok_or(PoolError::InvalidCurveVariant)?;
+        let variant = self
+            .variants
+            .get(index)
+            .ok_or(PoolError::InvalidCurveVariant)?;
+        require!(variant.is_initialized(), PoolError::InvalidCurveVariant);
+        Ok(variant)
+    }
+}
+
+/// apply curve variant to config, 0 means curve of config
+pub fn apply_curve_variant_to_config(
+    config_key: &Pubkey,
+    config: &mut PoolConfig,
+    curve_variant: u8,
+    curve_variants: Option<&CurveVariants>,
+) -> Result<()> {
+    if curve_variant != 0 {
+        let curve_variants = curve_variants.ok_or(PoolError::InvalidCurveVariant)?;
+        require!(
+            curve_variants.config.eq(config_key),
+            PoolError::InvalidAccount
+        );
+        config.apply_curve_variant(curve_variants.get_variant(curve_variant)?);
+    }
+    Ok(())
+}
+
+/// copy of config with curve variant applied, 0 means curve of config.
+/// Curve variants of config is the last remaining account, only passed when curve variant is not 0
+pub fn get_config_with_curve_variant<'c: 'info, 'info>(
+    config_key: &Pubkey,
+    config: &PoolConfig,
+    curve_variant: u8,
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<Box<PoolConfig>> {
+    let mut config_with_curve_variant = Box::new(*config);
+    if curve_variant != 0 {
+        let curve_variants_info = remaining_accounts
+            .last()
+            .ok_or(PoolError::InvalidCurveVariant)?;
+        let curve_variants_loader: AccountLoader<'info, CurveVariants> =
+            AccountLoader::try_from(curve_variants_info)?;
+        let curve_variants = curve_variants_loader.load()?;
+        apply_curve_variant_to_config(
+            config_key,
+            &mut config_with_curve_variant,
+            curve_variant,
+            Some(&curve_variants),
+        )?;
+    }
+    Ok(config_with_curve_variant)
+}
+
+/// copy of config with curve variant of the pool applied, swap and migration math of the pool must use it
+pub fn get_pool_config<'c: 'info, 'info>(
+    config: &PoolConfig,
+    pool: &VirtualPool,
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<Box<PoolConfig>> {
+    get_config_with_curve_variant(&pool.config, config, pool.curve_variant, remaining_accounts)
+}
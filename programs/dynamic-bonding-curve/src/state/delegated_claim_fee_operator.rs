@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+
+/// scope of delegated operator
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+pub enum OperatorScope {
+    /// operator can claim fees of the configs that owner is fee claimer
+    Partner,
+    /// operator can claim fees of the pools that owner is creator
+    Creator,
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Operator that is allowed to claim fees on behalf of a partner or a creator
+pub struct DelegatedClaimFeeOperator {
+    /// owner, fee claimer of configs or creator of pools
+    pub owner: Pubkey,
+    /// operator
+    pub operator: Pubkey,
+    /// operator scope
+    pub scope: u8,
+    /// padding 0
+    pub _padding_0: [u8; 7],
+    /// Reserve
+    pub _padding: [u8; 64],
+}
+
+const_assert_eq!(DelegatedClaimFeeOperator::INIT_SPACE, 136);
+
+impl DelegatedClaimFeeOperator {
+    pub fn initialize(&mut self, owner: Pubkey, operator: Pubkey, scope: OperatorScope) {
+        self.owner = owner;
+        self.operator = operator;
+        self.scope = scope.into();
+    }
+
+    pub fn is_delegated(&self, owner: &Pubkey, scope: OperatorScope, operator: &Pubkey) -> bool {
+        self.owner.eq(owner) && self.scope == u8::from(scope) && self.operator.eq(operator)
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use ruint::aliases::U256;
 use static_assertions::const_assert_eq;
 
 use crate::{
@@ -105,6 +106,51 @@ impl VolatilityTracker {
         }
         Ok(())
     }
+
+    // circuit breaker refreshes the sqrt price reference by itself if dynamic fee is disabled
+    pub fn update_circuit_breaker_references(
+        &mut self,
+        circuit_breaker_window: u16,
+        sqrt_price_current: u128,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let elapsed = current_timestamp.safe_sub(self.last_update_timestamp)?;
+        if elapsed >= circuit_breaker_window.into() {
+            self.sqrt_price_reference = sqrt_price_current;
+            self.last_update_timestamp = current_timestamp;
+        }
+        Ok(())
+    }
+
+    // upper_price / lower_price <= 1 + max_price_change_bps / BASIS_POINT_MAX
+    pub fn is_price_change_within_limit(
+        &self,
+        sqrt_price: u128,
+        max_price_change_bps: u16,
+    ) -> Result<bool> {
+        if self.sqrt_price_reference == 0 {
+            return Ok(true);
+        }
+        let (upper_sqrt_price, lower_sqrt_price) = if sqrt_price > self.sqrt_price_reference {
+            (
+                U256::from(sqrt_price),
+                U256::from(self.sqrt_price_reference),
+            )
+        } else {
+            (
+                U256::from(self.sqrt_price_reference),
+                U256::from(sqrt_price),
+            )
+        };
+        let max_price_ratio = BASIS_POINT_MAX.safe_add(max_price_change_bps.into())?;
+        let upper_side = upper_sqrt_price
+            .safe_mul(upper_sqrt_price)?
+            .safe_mul(U256::from(BASIS_POINT_MAX))?;
+        let lower_side = lower_sqrt_price
+            .safe_mul(lower_sqrt_price)?
+            .safe_mul(U256::from(max_price_ratio))?;
+        Ok(upper_side <= lower_side)
+    }
 }
 
 #[derive(Default, Debug)]
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{BASIS_POINT_MAX, MAX_FEE_SPLIT_RECIPIENT},
+    safe_math::SafeMath,
+    u128x128_math::Rounding,
+    utils_math::safe_mul_div_cast_u64,
+    FeeSplitRecipientParams, PoolError,
+};
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct FeeSplitRecipient {
+    /// recipient
+    pub recipient: Pubkey,
+    /// share of partner revenue in basis point
+    pub weight_bps: u16,
+    /// padding 0
+    pub _padding_0: [u8; 6],
+    /// amount of quote token that recipient can claim
+    pub pending_amount: u64,
+    /// total amount of quote token that recipient has claimed
+    pub total_claimed_amount: u64,
+    /// amount of lamports from pool creation fee paid in SOL that recipient can claim
+    pub pending_sol_amount: u64,
+    /// total amount of lamports that recipient has claimed
+    pub total_claimed_sol_amount: u64,
+}
+
+const_assert_eq!(FeeSplitRecipient::INIT_SPACE, 72);
+
+impl FeeSplitRecipient {
+    pub fn is_initialized(&self) -> bool {
+        self.weight_bps != 0
+    }
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Split partner revenue (quote trading fee, surplus, migration fee and pool creation fee) of a config among many recipients,
+/// pool creation fee paid in SOL is held as lamports of this account
+pub struct FeeSplit {
+    /// config
+    pub config: Pubkey,
+    /// vault holding quote token that is not claimed by recipients
+    pub quote_vault: Pubkey,
+    /// recipients
+    pub recipients: [FeeSplitRecipient; MAX_FEE_SPLIT_RECIPIENT],
+    /// Reserve
+    pub _padding: [u8; 64],
+}
+
+const_assert_eq!(FeeSplit::INIT_SPACE, 704);
+
+impl FeeSplit {
+    pub fn initialize(
+        &mut self,
+        config: Pubkey,
+        quote_vault: Pubkey,
+        recipients: &[FeeSplitRecipientParams],
+    ) -> Result<()> {
+        validate_fee_split_recipients(recipients)?;
+        self.config = config;
+        self.quote_vault = quote_vault;
+        for (slot, params) in self.recipients.iter_mut().zip(recipients.iter()) {
+            slot.recipient = params.recipient;
+            slot.weight_bps = params.weight_bps;
+        }
+        Ok(())
+    }
+
+    /// credit recipients by weight, rounding dust goes to the last recipient
+    pub fn distribute(&mut self, amount: u64, sol_amount: u64) -> Result<()> {
+        if amount == 0 && sol_amount == 0 {
+            return Ok(());
+        }
+        let last_index = self
+            .recipients
+            .iter()
+            .rposition(|r| r.is_initialized())
+            .ok_or(PoolError::InvalidFeeSplitRecipients)?;
+
+        let mut remaining_amount = amount;
+        let mut remaining_sol_amount = sol_amount;
+        for recipient in self.recipients[..last_index].iter_mut() {
+            if !recipient.is_initialized() {
+                continue;
+            }
+            let share = safe_mul_div_cast_u64(
+                amount,
+                recipient.weight_bps.into(),
+                BASIS_POINT_MAX,
+                Rounding::Down,
+            )?;
+            let sol_share = safe_mul_div_cast_u64(
+                sol_amount,
+                recipient.weight_bps.into(),
+                BASIS_POINT_MAX,
+                Rounding::Down,
+            )?;
+            recipient.pending_amount = recipient.pending_amount.safe_add(share)?;
+            recipient.pending_sol_amount = recipient.pending_sol_amount.safe_add(sol_share)?;
+            remaining_amount = remaining_amount.safe_sub(share)?;
+            remaining_sol_amount = remaining_sol_amount.safe_sub(sol_share)?;
+        }
+
+        let last_recipient = &mut self.recipients[last_index];
+        last_recipient.pending_amount = last_recipient.pending_amount.safe_add(remaining_amount)?;
+        last_recipient.pending_sol_amount = last_recipient
+            .pending_sol_amount
+            .safe_add(remaining_sol_amount)?;
+        Ok(())
+    }
+
+    /// fee split can be closed only after recipients claimed everything
+    pub fn is_fully_claimed(&self) -> bool {
+        self.recipients
+            .iter()
+            .all(|r| r.pending_amount == 0 && r.pending_sol_amount == 0)
+    }
+
+    /// return pending quote and sol amount of the recipient and reset them
+    pub fn claim(&mut self, recipient: &Pubkey) -> Result<(u64, u64)> {
+        let recipient = self
+            .recipients
+            .iter_mut()
+            .find(|r| r.is_initialized() && r.recipient.eq(recipient))
+            .ok_or(PoolError::NotPermitToDoThisAction)?;
+
+        let amount = recipient.pending_amount;
+        let sol_amount = recipient.pending_sol_amount;
+        recipient.pending_amount = 0;
+        recipient.pending_sol_amount = 0;
+        recipient.total_claimed_amount = recipient.total_claimed_amount.safe_add(amount)?;
+        recipient.total_claimed_sol_amount =
+            recipient.total_claimed_sol_amount.safe_add(sol_amount)?;
+        Ok((amount, sol_amount))
+    }
+}
+
+/// recipients must be unique, non-default and weights must sum up to 100%
+pub fn validate_fee_split_recipients(recipients: &[FeeSplitRecipientParams]) -> Result<()> {
+    require!(
+        !recipients.is_empty() && recipients.len() <= MAX_FEE_SPLIT_RECIPIENT,
+        PoolError::InvalidFeeSplitRecipients
+    );
+
+    let mut total_weight_bps: u64 = 0;
+    for (i, params) in recipients.iter().enumerate() {
+        require!(
+            params.recipient != Pubkey::default()
+                && params.weight_bps > 0
+                && recipients[..i]
+                    .iter()
+                    .all(|r| r.recipient != params.recipient),
+            PoolError::InvalidFeeSplitRecipients
+        );
+        total_weight_bps = total_weight_bps.safe_add(params.weight_bps.into())?;
+    }
+    require!(
+        total_weight_bps == BASIS_POINT_MAX,
+        PoolError::InvalidFeeSplitRecipients
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Program wide state that set by the protocol
+pub struct GlobalState {
+    /// emergency stop, swap, pool creation and config creation are rejected when it is on
+    pub is_emergency_stopped: u8,
+    /// padding 0
+    pub _padding_0: [u8; 7],
+    /// Reserve
+    pub _padding: [u8; 128],
+}
+
+const_assert_eq!(GlobalState::INIT_SPACE, 136);
+
+impl GlobalState {
+    pub fn is_emergency_stopped(&self) -> bool {
+        self.is_emergency_stopped == 1
+    }
+
+    pub fn set_emergency_stopped(&mut self, is_emergency_stopped: bool) {
+        self.is_emergency_stopped = is_emergency_stopped.into();
+    }
+}
@@ -9,3 +9,17 @@ pub mod partner_metadata;
 pub use partner_metadata::*;
 pub mod virtual_pool_metadata;
 pub use virtual_pool_metadata::*;
+pub mod global_state;
+pub use global_state::*;
+pub mod protocol_config;
+pub use protocol_config::*;
+pub mod delegated_claim_fee_operator;
+pub use delegated_claim_fee_operator::*;
+pub mod fee_split;
+pub use fee_split::*;
+pub mod creator_fee_split;
+pub use creator_fee_split::*;
+pub mod curve_variants;
+pub use curve_variants::*;
+pub mod token_metadata_config;
+pub use token_metadata_config::*;
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{
+        MAX_POOL_CREATION_FEE_PROTOCOL_PERCENT, MAX_PROTOCOL_ADMIN, MAX_SURPLUS_SHARE_TIER,
+    },
+    PoolError,
+};
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Admins that are allowed to call protocol functions
+pub struct ProtocolConfig {
+    /// admins, default pubkey means empty slot
+    pub admins: [Pubkey; MAX_PROTOCOL_ADMIN],
+    /// admin that is proposed, must accept to be added
+    pub pending_admin: Pubkey,
+    /// admin that is replaced when pending admin accepts, default pubkey means pending admin is added to an empty slot
+    pub pending_replaced_admin: Pubkey,
+    /// approved partner and creator surplus shares (in percentage) that configs can use, 0 means empty slot
+    pub surplus_share_tiers: [u8; MAX_SURPLUS_SHARE_TIER],
+    /// protocol cut of pool creation fee in percentage, applied to configs created afterward
+    pub pool_creation_fee_protocol_percent: u8,
+    /// admin that is proposed to be removed, another admin must confirm the removal
+    pub pending_removed_admin: Pubkey,
+    /// admin that proposed the pending removal
+    pub remove_admin_proposer: Pubkey,
+    /// admin that proposed the pending admin
+    pub pending_admin_proposer: Pubkey,
+    /// Reserve
+    pub _padding: [u8; 23],
+}
+
+const_assert_eq!(ProtocolConfig::INIT_SPACE, 448);
+
+impl ProtocolConfig {
+    pub fn initialize(&mut self, admin: Pubkey) {
+        self.admins[0] = admin;
+    }
+
+    pub fn is_admin(&self, admin: &Pubkey) -> bool {
+        *admin != Pubkey::default() && self.admins.iter().any(|a| a.eq(admin))
+    }
+
+    pub fn get_admin_count(&self) -> usize {
+        self.admins
+            .iter()
+            .filter(|a| **a != Pubkey::default())
+            .count()
+    }
+
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admin != Pubkey::default()
+    }
+
+    /// an admin can only rotate itself, other admins are replaced with propose_remove_admin and confirm_remove_admin
+    pub fn propose_admin(
+        &mut self,
+        proposer: Pubkey,
+        new_admin: Pubkey,
+        replaced_admin: Pubkey,
+    ) -> Result<()> {
+        require!(self.is_admin(&proposer), PoolError::InvalidAdmin);
+        require!(
+            new_admin != Pubkey::default() && !self.is_admin(&new_admin),
+            PoolError::InvalidAdmin
+        );
+        if replaced_admin == Pubkey::default() {
+            require!(
+                self.get_admin_count() < MAX_PROTOCOL_ADMIN,
+                PoolError::ExceededMaxAdmin
+            );
+        } else {
+            require!(replaced_admin == proposer, PoolError::InvalidAdmin);
+        }
+        self.pending_admin = new_admin;
+        self.pending_replaced_admin = replaced_admin;
+        self.pending_admin_proposer = proposer;
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<()> {
+        require!(
+            self.is_admin(&self.pending_admin_proposer),
+            PoolError::InvalidAdmin
+        );
+        let index = self
+            .admins
+            .iter()
+            .position(|a| a.eq(&self.pending_replaced_admin))
+            .ok_or(PoolError::InvalidAdmin)?;
+        // pending removal proposed by or for the replaced admin is no longer valid
+        if self.pending_replaced_admin != Pubkey::default()
+            && (self.pending_replaced_admin == self.pending_removed_admin
+                || self.pending_replaced_admin == self.remove_admin_proposer)
+        {
+            self.clear_pending_removed_admin();
+        }
+        self.admins[index] = self.pending_admin;
+        self.clear_pending_admin();
+        Ok(())
+    }
+
+    pub fn has_pending_removed_admin(&self) -> bool {
+        self.pending_removed_admin != Pubkey::default()
+    }
+
+    pub fn propose_remove_admin(&mut self, proposer: Pubkey, removed_admin: Pubkey) -> Result<()> {
+        require!(self.is_admin(&removed_admin), PoolError::InvalidAdmin);
+        require!(self.get_admin_count() > 1, PoolError::CannotRemoveLastAdmin);
+        self.pending_removed_admin = removed_admin;
+        self.remove_admin_proposer = proposer;
+        Ok(())
+    }
+
+    /// removes the pending removed admin, the confirming admin must not be the proposer
+    pub fn confirm_remove_admin(&mut self, admin: Pubkey) -> Result<Pubkey> {
+        require!(self.has_pending_removed_admin(), PoolError::InvalidAdmin);
+        require!(
+            self.is_admin(&admin) && admin != self.remove_admin_proposer,
+            PoolError::InvalidAdmin
+        );
+        let removed_admin = self.pending_removed_admin;
+        self.remove_admin(removed_admin)?;
+        Ok(removed_admin)
+    }
+
+    fn remove_admin(&mut self, admin: Pubkey) -> Result<()> {
+        require!(self.is_admin(&admin), PoolError::InvalidAdmin);
+        require!(self.get_admin_count() > 1, PoolError::CannotRemoveLastAdmin);
+        let index = self
+            .admins
+            .iter()
+            .position(|a| a.eq(&admin))
+            .ok_or(PoolError::InvalidAdmin)?;
+        self.admins[index] = Pubkey::default();
+        // pending admin proposed by or replacing the removed admin is no longer valid
+        if self.pending_replaced_admin == admin || self.pending_admin_proposer == admin {
+            self.clear_pending_admin();
+        }
+        self.clear_pending_removed_admin();
+        Ok(())
+    }
+
+    pub fn is_approved_surplus_share(&self, surplus_share: u8) -> bool {
+        surplus_share != 0 && self.surplus_share_tiers.contains(&surplus_share)
+    }
+
+    pub fn update_surplus_share_tiers(
+        &mut self,
+        surplus_share_tiers: [u8; MAX_SURPLUS_SHARE_TIER],
+    ) -> Result<()> {
+        require!(
+            surplus_share_tiers.iter().all(|&share| share <= 100),
+            PoolError::InvalidSurplusShare
+        );
+        self.surplus_share_tiers = surplus_share_tiers;
+        Ok(())
+    }
+
+    pub fn update_pool_creation_fee_protocol_percent(&mut self, percent: u8) -> Result<()> {
+        require!(
+            percent <= MAX_POOL_CREATION_FEE_PROTOCOL_PERCENT,
+            PoolError::InvalidPoolCreationFee
+        );
+        self.pool_creation_fee_protocol_percent = percent;
+        Ok(())
+    }
+
+    pub fn clear_pending_admin(&mut self) {
+        self.pending_admin = Pubkey::default();
+        self.pending_replaced_admin = Pubkey::default();
+        self.pending_admin_proposer = Pubkey::default();
+    }
+
+    pub fn clear_pending_removed_admin(&mut self) {
+        self.pending_removed_admin = Pubkey::default();
+        self.remove_admin_proposer = Pubkey::default();
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{BASIS_POINT_MAX, MAX_TOKEN_METADATA_CREATOR},
+    safe_math::SafeMath,
+    state::PoolConfig,
+    PoolError, TokenMetadataConfigParameters,
+};
+
+#[zero_copy]
+#[derive(InitSpace, Debug, Default)]
+pub struct TokenMetadataCreator {
+    /// creator address, default pubkey means pool creator
+    pub address: Pubkey,
+    /// share of royalties in percentage
+    pub share: u8,
+}
+
+const_assert_eq!(TokenMetadataCreator::INIT_SPACE, 33);
+
+impl TokenMetadataCreator {
+    pub fn get_address(&self, pool_creator: Pubkey) -> Pubkey {
+        if self.address == Pubkey::default() {
+            pool_creator
+        } else {
+            self.address
+        }
+    }
+}
+
+#[account(zero_copy)]
+#[derive(InitSpace, Debug)]
+/// Metadata options of base tokens created on a config, applied to Metaplex metadata of spl token
+/// and additional metadata of token 2022 at pool creation
+pub struct TokenMetadataConfig {
+    /// config
+    pub config: Pubkey,
+    /// royalties in basis point
+    pub seller_fee_basis_points: u16,
+    /// number of creators
+    pub creator_count: u8,
+    /// whether metadata can be updated by update authority of token authority option
+    pub is_mutable: u8,
+    /// collection, default pubkey means no collection
+    pub collection: Pubkey,
+    /// creators
+    pub creators: [TokenMetadataCreator; MAX_TOKEN_METADATA_CREATOR],
+    /// Reserve
+    pub _padding: [u8; 23],
+}
+
+const_assert_eq!(TokenMetadataConfig::INIT_SPACE, 256);
+
+/// keys of token 2022 additional metadata written from token metadata config
+pub const SELLER_FEE_BASIS_POINTS_KEY: &str = "seller_fee_basis_points";
+pub const CREATORS_KEY: &str = "creators";
+pub const COLLECTION_KEY: &str = "collection";
+
+impl TokenMetadataConfig {
+    pub fn initialize(
+        &mut self,
+        config: Pubkey,
+        params: &TokenMetadataConfigParameters,
+    ) -> Result<()> {
+        params.validate()?;
+        self.config = config;
+        self.seller_fee_basis_points = params.seller_fee_basis_points;
+        self.creator_count = params.creators.len() as u8;
+        self.is_mutable = params.is_mutable.into();
+        self.collection = params.collection.unwrap_or_default();
+        for (slot, creator) in self.creators.iter_mut().zip(params.creators.iter()) {
+            slot.address = creator.address;
+            slot.share = creator.share;
+        }
+        Ok(())
+    }
+
+    pub fn is_mutable(&self) -> bool {
+        self.is_mutable == 1
+    }
+
+    pub fn get_collection(&self) -> Option<Pubkey> {
+        if self.collection == Pubkey::default() {
+            None
+        } else {
+            Some(self.collection)
+        }
+    }
+
+    /// creators with their shares, default pubkey is replaced by pool creator
+    pub fn get_creators(&self, pool_creator: Pubkey) -> Vec<(Pubkey, u8)> {
+        self.creators[..usize::from(self.creator_count)]
+            .iter()
+            .map(|creator| (creator.get_address(pool_creator), creator.share))
+            .collect()
+    }
+
+    /// key value fields of token 2022 additional metadata
+    pub fn get_additional_metadata(&self, pool_creator: Pubkey) -> Vec<(String, String)> {
+        let mut additional_metadata = vec![(
+            SELLER_FEE_BASIS_POINTS_KEY.to_string(),
+            self.seller_fee_basis_points.to_string(),
+        )];
+        let creators = self.get_creators(pool_creator);
+        if !creators.is_empty() {
+            let creators = creators
+                .iter()
+                .map(|(address, share)| format!("{address}:{share}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            additional_metadata.push((CREATORS_KEY.to_string(), creators));
+        }
+        if let Some(collection) = self.get_collection() {
+            additional_metadata.push((COLLECTION_KEY.to_string(), collection.to_string()));
+        }
+        additional_metadata
+    }
+}
+
+impl TokenMetadataConfigParameters {
+    /// creators must be unique and their shares must sum up to 100%, same as Metaplex metadata
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            u64::from(self.seller_fee_basis_points) <= BASIS_POINT_MAX
+                && self.creators.len() <= MAX_TOKEN_METADATA_CREATOR,
+            PoolError::InvalidTokenMetadataConfig
+        );
+        if self.creators.is_empty() {
+            return Ok(());
+        }
+
+        let mut total_share: u8 = 0;
+        for (i, creator) in self.creators.iter().enumerate() {
+            require!(
+                self.creators[..i]
+                    .iter()
+                    .all(|c| c.address != creator.address),
+                PoolError::InvalidTokenMetadataConfig
+            );
+            total_share = total_share.safe_add(creator.share)?;
+        }
+        require!(total_share == 100, PoolError::InvalidTokenMetadataConfig);
+        Ok(())
+    }
+}
+
+/// token metadata config of config, none if config has no token metadata config
+pub fn get_token_metadata_config(
+    config_key: &Pubkey,
+    config: &PoolConfig,
+    token_metadata_config: Option<&AccountLoader<'_, TokenMetadataConfig>>,
+) -> Result<Option<TokenMetadataConfig>> {
+    if !config.has_token_metadata_config() {
+        return Ok(None);
+    }
+    let token_metadata_config = token_metadata_config
+        .ok_or(PoolError::InvalidTokenMetadataConfig)?
+        .load()?;
+    require!(
+        token_metadata_config.config.eq(config_key),
+        PoolError::InvalidAccount
+    );
+    Ok(Some(*token_metadata_config))
+}
@@ -6,17 +6,16 @@ use ruint::aliases::U256;
 use static_assertions::const_assert_eq;
 
 use crate::{
-    constants::PARTNER_AND_CREATOR_SURPLUS_SHARE,
     curve::{
         get_delta_amount_base_unsigned, get_delta_amount_base_unsigned_256,
         get_delta_amount_quote_unsigned, get_delta_amount_quote_unsigned_256,
         get_next_sqrt_price_from_input,
     },
-    params::swap::TradeDirection,
+    params::{liquidity_distribution::get_migration_base_token, swap::TradeDirection},
     safe_math::SafeMath,
     state::{
         fee::{FeeMode, FeeOnAmountResult, VolatilityTracker},
-        PoolConfig,
+        MigrationOption, PoolConfig,
     },
     u128x128_math::Rounding,
     utils_math::safe_mul_div_cast_u64,
@@ -139,8 +138,22 @@ pub struct VirtualPool {
     pub creator_base_fee: u64,
     /// creator quote fee
     pub creator_quote_fee: u64,
+    /// is paused, swap is rejected while pool is paused
+    pub is_paused: u8,
+    /// flag to indicate whether creator revenue is split among co-creators of creator fee split account
+    pub creator_fee_split_flag: u8,
+    /// curve variant picked by creator, 0 means curve of config, otherwise 1-based index in curve variants account
+    pub curve_variant: u8,
+    /// flag to indicate whether base token can only move through swaps until migration, base token accounts are frozen outside of swap
+    pub non_transferable_flag: u8,
+    /// padding 0
+    pub _padding_0: [u8; 4],
+    /// pending creator, must accept to become the pool creator
+    pub pending_creator: Pubkey,
+    /// number of base token accounts frozen by the pool, non-transferable pool can't be closed until they are thawed
+    pub frozen_account_count: u64,
     /// Padding for further use
-    pub _padding_1: [u64; 7],
+    pub _padding_1: [u64; 1],
 }
 
 const_assert_eq!(VirtualPool::INIT_SPACE, 416);
@@ -503,10 +516,85 @@ impl VirtualPool {
                 self.sqrt_price,
                 current_timestamp,
             )?;
+        } else if config.is_circuit_breaker_enabled() {
+            self.volatility_tracker.update_circuit_breaker_references(
+                config.circuit_breaker_window,
+                self.sqrt_price,
+                current_timestamp,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// circuit breaker rejects the swap if price moves too far from the reference price
+    pub fn is_circuit_breaker_triggered(
+        &self,
+        config: &PoolConfig,
+        next_sqrt_price: u128,
+    ) -> Result<bool> {
+        if !config.is_circuit_breaker_enabled() {
+            return Ok(false);
         }
+        Ok(!self.volatility_tracker.is_price_change_within_limit(
+            next_sqrt_price,
+            config.circuit_breaker_max_price_change_bps,
+        )?)
+    }
+
+    pub fn has_pending_creator(&self) -> bool {
+        self.pending_creator != Pubkey::default()
+    }
+
+    pub fn has_creator_fee_split(&self) -> bool {
+        self.creator_fee_split_flag == 1
+    }
+
+    pub fn has_curve_variant(&self) -> bool {
+        self.curve_variant != 0
+    }
+
+    pub fn is_non_transferable(&self) -> bool {
+        self.non_transferable_flag == 1
+    }
+
+    pub fn has_frozen_account(&self) -> bool {
+        self.frozen_account_count != 0
+    }
+
+    pub fn increase_frozen_account_count(&mut self) -> Result<()> {
+        self.frozen_account_count = self.frozen_account_count.safe_add(1)?;
+        Ok(())
+    }
+
+    pub fn decrease_frozen_account_count(&mut self) -> Result<()> {
+        self.frozen_account_count = self.frozen_account_count.safe_sub(1)?;
         Ok(())
     }
 
+    /// base token account of a holder is frozen after every swap until migration, it is counted the first time it is frozen
+    pub fn track_frozen_account(&mut self, is_frozen: bool) -> Result<()> {
+        if !is_frozen {
+            self.increase_frozen_account_count()?;
+        }
+        Ok(())
+    }
+
+    /// base token account is thawed after migration, it is uncounted if it was frozen
+    pub fn untrack_frozen_account(&mut self, is_frozen: bool) -> Result<()> {
+        if is_frozen {
+            self.decrease_frozen_account_count()?;
+        }
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused == 1
+    }
+
+    pub fn set_paused(&mut self, is_paused: bool) {
+        self.is_paused = is_paused.into();
+    }
+
     pub fn update_post_swap(
         &mut self,
         config: &PoolConfig,
@@ -531,6 +619,17 @@ impl VirtualPool {
         Ok(())
     }
 
+    /// pool creation fee paid in quote token is claimed with trading fees
+    pub fn accumulate_pool_creation_fee(
+        &mut self,
+        partner_fee: u64,
+        protocol_fee: u64,
+    ) -> Result<()> {
+        self.partner_quote_fee = self.partner_quote_fee.safe_add(partner_fee)?;
+        self.protocol_quote_fee = self.protocol_quote_fee.safe_add(protocol_fee)?;
+        Ok(())
+    }
+
     pub fn claim_protocol_fee(&mut self) -> (u64, u64) {
         let token_base_amount = self.protocol_base_fee;
         let token_quote_amount = self.protocol_quote_fee;
@@ -551,6 +650,24 @@ impl VirtualPool {
         Ok((token_base_amount, token_quote_amount))
     }
 
+    /// claim all partner trading fee of a pool in batch claim, the pool must be on the config and match the base mint and vaults
+    pub fn batch_claim_partner_trading_fee(
+        &mut self,
+        config: &Pubkey,
+        base_mint: &Pubkey,
+        base_vault: &Pubkey,
+        quote_vault: &Pubkey,
+    ) -> Result<(u64, u64)> {
+        require!(
+            self.config == *config
+                && self.base_mint == *base_mint
+                && self.base_vault == *base_vault
+                && self.quote_vault == *quote_vault,
+            PoolError::InvalidAccount
+        );
+        self.claim_partner_trading_fee(u64::MAX, u64::MAX)
+    }
+
     pub fn claim_creator_trading_fee(
         &mut self,
         max_base_amount: u64,
@@ -574,6 +691,138 @@ impl VirtualPool {
         self.quote_reserve >= migration_threshold
     }
 
+    pub fn is_migration_deadline_reached(
+        &self,
+        config: &PoolConfig,
+        current_point: u64,
+    ) -> Result<bool> {
+        if !config.has_migration_deadline() {
+            return Ok(false);
+        }
+        let migration_deadline = self
+            .activation_point
+            .safe_add(config.migration_deadline_duration)?;
+        Ok(current_point >= migration_deadline)
+    }
+
+    /// curve can be completed without reaching migration quote threshold, when the deadline is reached and the minimum quote amount is raised
+    pub fn can_complete_curve_by_deadline(
+        &self,
+        config: &PoolConfig,
+        current_point: u64,
+    ) -> Result<bool> {
+        Ok(self.is_migration_deadline_reached(config, current_point)?
+            && self.quote_reserve >= config.min_migration_quote_threshold)
+    }
+
+    /// pool missed the deadline without raising the minimum quote amount, it can't be completed anymore
+    pub fn is_migration_deadline_missed(
+        &self,
+        config: &PoolConfig,
+        current_point: u64,
+    ) -> Result<bool> {
+        Ok(self.is_migration_deadline_reached(config, current_point)?
+            && self.quote_reserve < config.min_migration_quote_threshold)
+    }
+
+    /// curve has been completed at the deadline with quote reserve below migration quote threshold
+    pub fn is_curve_complete_by_deadline(&self, config: &PoolConfig) -> bool {
+        self.migration_progress != u8::from(MigrationProgress::PreBondingCurve)
+            && self.quote_reserve < config.migration_quote_threshold
+    }
+
+    /// all quote reserve is migrated if curve is completed by deadline
+    pub fn get_migration_quote_threshold(&self, config: &PoolConfig) -> u64 {
+        if self.is_curve_complete_by_deadline(config) {
+            self.quote_reserve
+        } else {
+            config.migration_quote_threshold
+        }
+    }
+
+    pub fn get_migration_sqrt_price(&self, config: &PoolConfig) -> u128 {
+        if self.is_curve_complete_by_deadline(config) {
+            self.sqrt_price
+        } else {
+            config.migration_sqrt_price
+        }
+    }
+
+    pub fn get_migration_base_threshold(&self, config: &PoolConfig) -> Result<u64> {
+        if self.is_curve_complete_by_deadline(config) {
+            let migration_option = MigrationOption::try_from(config.migration_option)
+                .map_err(|_| PoolError::InvalidMigrationOption)?;
+            get_migration_base_token(
+                self.quote_reserve,
+                config.migration_fee_percentage,
+                self.sqrt_price,
+                migration_option,
+            )
+        } else {
+            Ok(config.migration_base_threshold)
+        }
+    }
+
+    /// base token that is reserved for the curve and migration but is not used because curve is completed by deadline
+    pub fn get_unsold_base_amount(&self, config: &PoolConfig) -> Result<u64> {
+        if !self.is_curve_complete_by_deadline(config) {
+            return Ok(0);
+        }
+        let sold_base_amount = config
+            .get_initial_base_supply()?
+            .saturating_sub(self.base_reserve);
+        let unsold_swap_base_amount = config.swap_base_amount.saturating_sub(sold_base_amount);
+        let unused_migration_base_amount = config
+            .migration_base_threshold
+            .saturating_sub(self.get_migration_base_threshold(config)?);
+        Ok(unsold_swap_base_amount.safe_add(unused_migration_base_amount)?)
+    }
+
+    /// leftover is extra base token in base vault after curve is completed
+    pub fn get_burnable_amount_post_migration(
+        &self,
+        config: &PoolConfig,
+        leftover: u64,
+    ) -> Result<u64> {
+        let max_burnable_amount = config.get_max_burnable_amount_post_migration()?;
+        // unsold base token is burned, so leftover receiver only get the same amount as in a fully filled curve
+        let max_burnable_amount = if config.is_fixed_token_supply() {
+            max_burnable_amount.safe_add(self.get_unsold_base_amount(config)?)?
+        } else {
+            max_burnable_amount
+        };
+        Ok(max_burnable_amount.min(leftover))
+    }
+
+    pub fn complete_curve(
+        &mut self,
+        config: &PoolConfig,
+        base_vault_balance: u64,
+        current_timestamp: u64,
+    ) -> Result<()> {
+        let locked_vesting_params = config.locked_vesting_config.to_locked_vesting_params();
+        if locked_vesting_params.has_vesting() {
+            self.set_migration_progress(MigrationProgress::PostBondingCurve.into());
+        } else {
+            self.set_migration_progress(MigrationProgress::LockedVesting.into());
+        }
+
+        // validate if base reserve is enough token for migration
+        let required_base_balance = self
+            .get_migration_base_threshold(config)?
+            .safe_add(self.get_protocol_and_trading_base_fee()?)?
+            .safe_add(locked_vesting_params.get_total_amount()?)?;
+
+        require!(
+            base_vault_balance >= required_base_balance,
+            PoolError::InsufficientLiquidityForMigration
+        );
+
+        // set finish time
+        self.finish_curve_timestamp = current_timestamp;
+        Ok(())
+    }
+
     pub fn update_after_create_pool(&mut self) {
         self.is_migrated = 1;
     }
@@ -582,10 +831,14 @@ impl VirtualPool {
         Ok(self.quote_reserve.safe_sub(migration_threshold)?)
     }
 
-    fn get_partner_and_creator_surplus(&self, total_surplus: u64) -> Result<u64> {
+    fn get_partner_and_creator_surplus(
+        &self,
+        config: &PoolConfig,
+        total_surplus: u64,
+    ) -> Result<u64> {
         let partner_and_creator_surplus = safe_mul_div_cast_u64(
             total_surplus,
-            PARTNER_AND_CREATOR_SURPLUS_SHARE.into(),
+            config.get_partner_and_creator_surplus_share().into(),
             100,
             Rounding::Down,
         )?;
@@ -593,26 +846,32 @@ impl VirtualPool {
     }
 
     pub fn get_partner_surplus(&self, config: &PoolConfig, total_surplus: u64) -> Result<u64> {
-        let partner_and_creator_surplus = self.get_partner_and_creator_surplus(total_surplus)?;
+        let partner_and_creator_surplus =
+            self.get_partner_and_creator_surplus(config, total_surplus)?;
 
         let PartnerAndCreatorSplitFee { partner_fee, .. } =
-            config.split_partner_and_creator_fee(partner_and_creator_surplus)?;
+            config.split_partner_and_creator_surplus(partner_and_creator_surplus)?;
 
         Ok(partner_fee)
     }
 
     pub fn get_creator_surplus(&self, config: &PoolConfig, total_surplus: u64) -> Result<u64> {
-        let partner_and_creator_surplus = self.get_partner_and_creator_surplus(total_surplus)?;
+        let partner_and_creator_surplus =
+            self.get_partner_and_creator_surplus(config, total_surplus)?;
 
         let PartnerAndCreatorSplitFee { creator_fee, .. } =
-            config.split_partner_and_creator_fee(partner_and_creator_surplus)?;
+            config.split_partner_and_creator_surplus(partner_and_creator_surplus)?;
 
         Ok(creator_fee)
     }
 
-    pub fn get_protocol_surplus(&self, migration_threshold: u64) -> Result<u64> {
+    pub fn get_protocol_surplus(
+        &self,
+        config: &PoolConfig,
+        migration_threshold: u64,
+    ) -> Result<u64> {
         let total_surplus: u64 = self.get_total_surplus(migration_threshold)?;
-        let partner_surplus_amount = self.get_partner_and_creator_surplus(total_surplus)?;
+        let partner_surplus_amount = self.get_partner_and_creator_surplus(config, total_surplus)?;
         Ok(total_surplus.safe_sub(partner_surplus_amount)?)
     }
 
@@ -639,6 +898,29 @@ impl VirtualPool {
         self.migration_fee_withdraw_status = self.migration_fee_withdraw_status.bitxor(mask)
     }
 
+    /// every claimable balance is zero and every withdrawal is done after pool is migrated
+    pub fn is_fully_withdrawn(&self, config: &PoolConfig) -> Result<bool> {
+        let is_fee_claimed = self.get_protocol_and_trading_base_fee()? == 0
+            && self.protocol_quote_fee == 0
+            && self.partner_quote_fee == 0
+            && self.creator_quote_fee == 0;
+        let is_surplus_withdrawn = self.is_partner_withdraw_surplus == 1
+            && self.is_creator_withdraw_surplus == 1
+            && self.is_protocol_withdraw_surplus == 1;
+        let is_migration_fee_withdrawn = !self.eligible_to_withdraw_migration_fee(PARTNER_MASK)
+            && !self.eligible_to_withdraw_migration_fee(CREATOR_MASK);
+        // leftover can only be withdrawn if token is fixed supply
+        let is_leftover_withdrawn =
+            !config.is_fixed_token_supply() || self.is_withdraw_leftover == 1;
+        Ok(
+            self.get_migration_progress()? == MigrationProgress::CreatedPool
+                && is_fee_claimed
+                && is_surplus_withdrawn
+                && is_migration_fee_withdrawn
+                && is_leftover_withdrawn,
+        )
+    }
+
     pub fn get_migration_progress(&self) -> Result<MigrationProgress> {
         let migration_progress = MigrationProgress::try_from(self.migration_progress)
             .map_err(|_| PoolError::TypeCastFailed)?;
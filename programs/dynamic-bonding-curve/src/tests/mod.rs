@@ -21,3 +21,56 @@ mod test_rate_limiter;
 
 #[cfg(test)]
 mod dynamic_fee_params;
+
+#[cfg(test)]
+mod test_migration_deadline;
+
+#[cfg(test)]
+mod test_circuit_breaker;
+
+#[cfg(test)]
+mod test_protocol_config;
+
+#[cfg(test)]
+mod test_delegated_operator;
+
+#[cfg(test)]
+mod test_fee_split;
+
+#[cfg(test)]
+mod test_surplus_share;
+
+#[cfg(test)]
+mod test_pool_creation_fee;
+
+#[cfg(test)]
+mod test_pool_creation_approval;
+
+#[cfg(test)]
+mod test_curve_variants;
+
+#[cfg(test)]
+mod test_update_config;
+
+#[cfg(test)]
+mod test_close_virtual_pool;
+
+#[cfg(test)]
+mod test_base_transfer_fee;
+#[cfg(test)]
+mod test_token_metadata_config;
+
+#[cfg(test)]
+mod test_update_token_metadata;
+
+#[cfg(test)]
+mod test_transfer_fee_claimer;
+
+#[cfg(test)]
+mod test_batch_claim_trading_fee;
+
+#[cfg(test)]
+mod test_existing_base_mint;
+
+#[cfg(test)]
+mod test_non_transferable;
@@ -0,0 +1,102 @@
+use crate::{
+    state::{LockedVestingConfig, PoolConfig},
+    token::{
+        calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+        TransferFeeExcludedAmount, TransferFeeIncludedAmount,
+    },
+};
+
+fn get_config(base_transfer_fee_bps: u16, base_max_transfer_fee: u64) -> PoolConfig {
+    let mut config = PoolConfig {
+        migration_base_threshold: 1_000_000,
+        locked_vesting_config: LockedVestingConfig {
+            cliff_unlock_amount: 100_000,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    config.pool_fees.base_transfer_fee_bps = base_transfer_fee_bps;
+    config.pool_fees.base_max_transfer_fee = base_max_transfer_fee;
+    config
+}
+
+#[test]
+fn test_transfer_fee_excluded_amount() {
+    let transfer_fee = get_config(100, 5_000).get_base_transfer_fee();
+
+    let TransferFeeExcludedAmount {
+        amount,
+        transfer_fee: fee,
+    } = calculate_transfer_fee_excluded_amount(Some(&transfer_fee), 100_000).unwrap();
+    assert_eq!(fee, 1_000);
+    assert_eq!(amount, 99_000);
+
+    // fee is capped by max transfer fee
+    let TransferFeeExcludedAmount {
+        amount,
+        transfer_fee: fee,
+    } = calculate_transfer_fee_excluded_amount(Some(&transfer_fee), 1_000_000).unwrap();
+    assert_eq!(fee, 5_000);
+    assert_eq!(amount, 995_000);
+
+    let TransferFeeExcludedAmount {
+        amount,
+        transfer_fee: fee,
+    } = calculate_transfer_fee_excluded_amount(None, 1_000_000).unwrap();
+    assert_eq!(fee, 0);
+    assert_eq!(amount, 1_000_000);
+}
+
+#[test]
+fn test_transfer_fee_included_amount() {
+    let transfer_fee = get_config(100, 5_000).get_base_transfer_fee();
+
+    // destination receives exactly the excluded amount
+    for excluded_amount in [1, 99, 99_000, 1_000_000] {
+        let TransferFeeIncludedAmount { amount, .. } =
+            calculate_transfer_fee_included_amount(Some(&transfer_fee), excluded_amount).unwrap();
+        let TransferFeeExcludedAmount {
+            amount: received_amount,
+            ..
+        } = calculate_transfer_fee_excluded_amount(Some(&transfer_fee), amount).unwrap();
+        assert_eq!(received_amount, excluded_amount);
+    }
+
+    // fee is capped by max transfer fee
+    let TransferFeeIncludedAmount {
+        amount,
+        transfer_fee: fee,
+    } = calculate_transfer_fee_included_amount(Some(&transfer_fee), 1_000_000).unwrap();
+    assert_eq!(fee, 5_000);
+    assert_eq!(amount, 1_005_000);
+
+    let TransferFeeIncludedAmount {
+        amount,
+        transfer_fee: fee,
+    } = calculate_transfer_fee_included_amount(Some(&transfer_fee), 0).unwrap();
+    assert_eq!(fee, 0);
+    assert_eq!(amount, 0);
+}
+
+#[test]
+fn test_base_transfer_fee_buffer() {
+    assert_eq!(get_config(0, 0).get_base_transfer_fee_buffer().unwrap(), 0);
+
+    // migration fee 10_000, locked vesting fee 1_000 and 1 more max fee for the second position
+    assert_eq!(
+        get_config(100, 50_000)
+            .get_base_transfer_fee_buffer()
+            .unwrap(),
+        61_000
+    );
+
+    // fixed supply token pays transfer fee from leftover
+    let fixed_supply_config = PoolConfig {
+        fixed_token_supply_flag: 1,
+        ..get_config(100, 50_000)
+    };
+    assert_eq!(
+        fixed_supply_config.get_base_transfer_fee_buffer().unwrap(),
+        0
+    );
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+
+use crate::{
+    state::VirtualPool,
+    token::{
+        calculate_transfer_fee_excluded_amount, calculate_transfer_fee_excluded_amount_of_transfers,
+    },
+};
+
+#[test]
+fn test_batch_claim_partner_trading_fee() {
+    let config = Pubkey::new_unique();
+    let base_mint = Pubkey::new_unique();
+    let base_vault = Pubkey::new_unique();
+    let quote_vault = Pubkey::new_unique();
+    let mut pool = VirtualPool {
+        config,
+        base_mint,
+        base_vault,
+        quote_vault,
+        partner_base_fee: 2_000,
+        partner_quote_fee: 1_000,
+        creator_base_fee: 700,
+        creator_quote_fee: 500,
+        ..Default::default()
+    };
+
+    // pool of another config or with other base mint or vaults is rejected
+    assert!(pool
+        .batch_claim_partner_trading_fee(
+            &Pubkey::new_unique(),
+            &base_mint,
+            &base_vault,
+            &quote_vault
+        )
+        .is_err());
+    assert!(pool
+        .batch_claim_partner_trading_fee(&config, &Pubkey::new_unique(), &base_vault, &quote_vault)
+        .is_err());
+    assert!(pool
+        .batch_claim_partner_trading_fee(&config, &base_mint, &quote_vault, &quote_vault)
+        .is_err());
+    assert!(pool
+        .batch_claim_partner_trading_fee(&config, &base_mint, &base_vault, &base_vault)
+        .is_err());
+    assert_eq!(pool.partner_base_fee, 2_000);
+    assert_eq!(pool.partner_quote_fee, 1_000);
+
+    // base and quote fees are claimed together
+    assert_eq!(
+        pool.batch_claim_partner_trading_fee(&config, &base_mint, &base_vault, &quote_vault)
+            .unwrap(),
+        (2_000, 1_000)
+    );
+    assert_eq!(pool.partner_base_fee, 0);
+    assert_eq!(pool.partner_quote_fee, 0);
+    // creator fee is untouched
+    assert_eq!(pool.creator_base_fee, 700);
+    assert_eq!(pool.creator_quote_fee, 500);
+
+    // nothing left to claim
+    assert_eq!(
+        pool.batch_claim_partner_trading_fee(&config, &base_mint, &base_vault, &quote_vault)
+            .unwrap(),
+        (0, 0)
+    );
+}
+
+#[test]
+fn test_batch_claim_quote_amount_received_by_fee_split() {
+    let transfer_fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: 500.into(),
+        transfer_fee_basis_points: 100.into(),
+    };
+    // quote fee of each pool is sent in its own transfer
+    let token_quote_amounts = [100_000, 30_000, 150];
+
+    // every transfer pays its own rounded fee, the first one is capped by maximum fee
+    let amount = calculate_transfer_fee_excluded_amount_of_transfers(
+        Some(&transfer_fee),
+        &token_quote_amounts,
+    )
+    .unwrap();
+    assert_eq!(amount, (100_000 - 500) + (30_000 - 300) + (150 - 2));
+
+    // a single transfer of the total pays only one capped fee, which overstates the received amount
+    let total_amount: u64 = token_quote_amounts.iter().sum();
+    let single_transfer_amount =
+        calculate_transfer_fee_excluded_amount(Some(&transfer_fee), total_amount)
+            .unwrap()
+            .amount;
+    assert_eq!(single_transfer_amount, total_amount - 500);
+    assert!(amount < single_transfer_amount);
+
+    // without transfer fee, every token is received
+    assert_eq!(
+        calculate_transfer_fee_excluded_amount_of_transfers(None, &token_quote_amounts).unwrap(),
+        total_amount
+    );
+}
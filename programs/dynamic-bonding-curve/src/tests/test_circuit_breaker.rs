@@ -0,0 +1,62 @@
+use crate::{
+    constants::ONE_Q64,
+    state::{PoolConfig, VirtualPool},
+};
+
+#[test]
+fn test_circuit_breaker() {
+    let config = PoolConfig {
+        circuit_breaker_max_price_change_bps: 1_000, // 10%
+        circuit_breaker_window: 60,
+        ..Default::default()
+    };
+    let mut pool = VirtualPool {
+        sqrt_price: ONE_Q64,
+        ..Default::default()
+    };
+
+    pool.update_pre_swap(&config, 1_000).unwrap();
+    assert_eq!(pool.volatility_tracker.sqrt_price_reference, ONE_Q64);
+
+    // sqrt price * 1.04 => price * 1.0816
+    let next_sqrt_price = ONE_Q64 * 104 / 100;
+    assert!(!pool
+        .is_circuit_breaker_triggered(&config, next_sqrt_price)
+        .unwrap());
+
+    // sqrt price * 1.05 => price * 1.1025
+    let next_sqrt_price = ONE_Q64 * 105 / 100;
+    assert!(pool
+        .is_circuit_breaker_triggered(&config, next_sqrt_price)
+        .unwrap());
+
+    // sqrt price * 0.95 => price * 0.9025
+    let next_sqrt_price = ONE_Q64 * 95 / 100;
+    assert!(pool
+        .is_circuit_breaker_triggered(&config, next_sqrt_price)
+        .unwrap());
+
+    // reference is kept within the window
+    pool.sqrt_price = ONE_Q64 * 104 / 100;
+    pool.update_pre_swap(&config, 1_059).unwrap();
+    assert_eq!(pool.volatility_tracker.sqrt_price_reference, ONE_Q64);
+
+    // reference is refreshed after the window
+    pool.update_pre_swap(&config, 1_060).unwrap();
+    assert_eq!(
+        pool.volatility_tracker.sqrt_price_reference,
+        pool.sqrt_price
+    );
+}
+
+#[test]
+fn test_circuit_breaker_disabled() {
+    let config = PoolConfig::default();
+    let pool = VirtualPool {
+        sqrt_price: ONE_Q64,
+        ..Default::default()
+    };
+    assert!(!pool
+        .is_circuit_breaker_triggered(&config, u128::MAX)
+        .unwrap());
+}
@@ -0,0 +1,93 @@
+use crate::state::{MigrationProgress, PoolConfig, VirtualPool, CREATOR_MASK, PARTNER_MASK};
+
+fn get_withdrawn_pool() -> VirtualPool {
+    let mut pool = VirtualPool {
+        is_partner_withdraw_surplus: 1,
+        is_creator_withdraw_surplus: 1,
+        is_protocol_withdraw_surplus: 1,
+        ..Default::default()
+    };
+    pool.set_migration_progress(MigrationProgress::CreatedPool.into());
+    pool.update_withdraw_migration_fee(PARTNER_MASK);
+    pool.update_withdraw_migration_fee(CREATOR_MASK);
+    pool
+}
+
+#[test]
+fn test_is_fully_withdrawn() {
+    let config = PoolConfig::default();
+    let pool = get_withdrawn_pool();
+    assert!(pool.is_fully_withdrawn(&config).unwrap());
+
+    // leftover must be withdrawn if token is fixed supply
+    let fixed_supply_config = PoolConfig {
+        fixed_token_supply_flag: 1,
+        ..Default::default()
+    };
+    assert!(!pool.is_fully_withdrawn(&fixed_supply_config).unwrap());
+    let mut leftover_withdrawn_pool = pool;
+    leftover_withdrawn_pool.update_withdraw_leftover();
+    assert!(leftover_withdrawn_pool
+        .is_fully_withdrawn(&fixed_supply_config)
+        .unwrap());
+}
+
+#[test]
+fn test_is_not_fully_withdrawn() {
+    let config = PoolConfig::default();
+
+    let mut not_migrated_pool = get_withdrawn_pool();
+    not_migrated_pool.set_migration_progress(MigrationProgress::LockedVesting.into());
+    assert!(!not_migrated_pool.is_fully_withdrawn(&config).unwrap());
+
+    let mut unclaimed_fee_pool = get_withdrawn_pool();
+    unclaimed_fee_pool.creator_quote_fee = 1;
+    assert!(!unclaimed_fee_pool.is_fully_withdrawn(&config).unwrap());
+
+    let mut unclaimed_base_fee_pool = get_withdrawn_pool();
+    unclaimed_base_fee_pool.protocol_base_fee = 1;
+    assert!(!unclaimed_base_fee_pool.is_fully_withdrawn(&config).unwrap());
+
+    let mut surplus_pool = get_withdrawn_pool();
+    surplus_pool.is_protocol_withdraw_surplus = 0;
+    assert!(!surplus_pool.is_fully_withdrawn(&config).unwrap());
+
+    let mut migration_fee_pool = get_withdrawn_pool();
+    migration_fee_pool.migration_fee_withdraw_status = PARTNER_MASK;
+    assert!(!migration_fee_pool.is_fully_withdrawn(&config).unwrap());
+}
+
+#[test]
+fn test_decrease_pool_count() {
+    let mut config = PoolConfig {
+        pool_count_flag: 1,
+        ..Default::default()
+    };
+    config.increase_pool_count().unwrap();
+    config.decrease_pool_count().unwrap();
+    assert!(config.has_no_pool());
+    assert!(config.decrease_pool_count().is_err());
+
+    // pools created before pool counting are not counted
+    let mut legacy_config = PoolConfig::default();
+    legacy_config.decrease_pool_count().unwrap();
+    assert_eq!(legacy_config.pool_count, 0);
+}
+
+#[test]
+fn test_frozen_account_count() {
+    let mut pool = get_withdrawn_pool();
+    assert!(!pool.has_frozen_account());
+
+    pool.increase_frozen_account_count().unwrap();
+    pool.increase_frozen_account_count().unwrap();
+    assert!(pool.has_frozen_account());
+
+    pool.decrease_frozen_account_count().unwrap();
+    assert!(pool.has_frozen_account());
+    pool.decrease_frozen_account_count().unwrap();
+    assert!(!pool.has_frozen_account());
+
+    // can't thaw more accounts than the pool froze
+    assert!(pool.decrease_frozen_account_count().is_err());
+}
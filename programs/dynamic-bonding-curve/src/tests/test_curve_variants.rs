@@ -0,0 +1,181 @@
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+use crate::{
+    constants::MAX_SQRT_PRICE,
+    params::liquidity_distribution::LiquidityDistributionParameters,
+    state::{apply_curve_variant_to_config, CurveVariants, MigrationOption, PoolConfig},
+    CurveVariantParameters,
+};
+
+use super::price_math::get_price_from_id;
+
+fn get_curve() -> Vec<LiquidityDistributionParameters> {
+    vec![LiquidityDistributionParameters {
+        sqrt_price: MAX_SQRT_PRICE,
+        liquidity: 1_000_000_000_000_000_000_000_000u128
+            .checked_shl(64)
+            .unwrap(),
+    }]
+}
+
+fn get_variant_params(
+    sqrt_active_id: i32,
+    migration_quote_threshold: u64,
+) -> CurveVariantParameters {
+    CurveVariantParameters {
+        sqrt_start_price: get_price_from_id(sqrt_active_id, 80).unwrap(),
+        migration_quote_threshold,
+        curve: get_curve(),
+    }
+}
+
+fn get_config() -> PoolConfig {
+    let mut config = PoolConfig {
+        migration_option: MigrationOption::MeteoraDamm.into(),
+        migration_quote_threshold: 50_000_000_000,
+        sqrt_start_price: get_price_from_id(-100, 80).unwrap(),
+        ..Default::default()
+    };
+    config.curve[0] = get_curve()[0].to_liquidity_distribution_config();
+    config
+}
+
+#[test]
+fn test_curve_variant_changes_pool_math() {
+    let config = get_config();
+    let mut curve_variants = CurveVariants::zeroed();
+    curve_variants
+        .initialize(
+            Default::default(),
+            &config,
+            &[
+                get_variant_params(-200, 10_000_000_000),
+                get_variant_params(-50, 200_000_000_000),
+            ],
+        )
+        .unwrap();
+
+    let small_variant = curve_variants.get_variant(1).unwrap();
+    let large_variant = curve_variants.get_variant(2).unwrap();
+    assert_eq!(small_variant.migration_quote_threshold, 10_000_000_000);
+    assert!(small_variant.swap_base_amount > 0 && small_variant.migration_base_threshold > 0);
+
+    let mut small_config = config;
+    small_config.apply_curve_variant(small_variant);
+    let mut large_config = config;
+    large_config.apply_curve_variant(large_variant);
+
+    assert_eq!(small_config.migration_quote_threshold, 10_000_000_000);
+    assert_eq!(
+        small_config.sqrt_start_price,
+        small_variant.sqrt_start_price
+    );
+    assert_eq!(small_config.curve[0].sqrt_price, MAX_SQRT_PRICE);
+    assert_ne!(
+        small_config.get_initial_base_supply().unwrap(),
+        large_config.get_initial_base_supply().unwrap()
+    );
+}
+
+#[test]
+fn test_curve_variant_index() {
+    let config = get_config();
+    let mut curve_variants = CurveVariants::zeroed();
+    curve_variants
+        .initialize(
+            Default::default(),
+            &config,
+            &[get_variant_params(-200, 10_000_000_000)],
+        )
+        .unwrap();
+
+    // 0 is curve of config
+    assert!(curve_variants.get_variant(0).is_err());
+    assert!(curve_variants.get_variant(1).is_ok());
+    assert!(curve_variants.get_variant(2).is_err());
+    assert!(curve_variants.get_variant(u8::MAX).is_err());
+}
+
+#[test]
+fn test_apply_curve_variant_to_config() {
+    let config_key = Pubkey::new_unique();
+    let config = get_config();
+    let mut curve_variants = CurveVariants::zeroed();
+    curve_variants
+        .initialize(
+            config_key,
+            &config,
+            &[get_variant_params(-200, 10_000_000_000)],
+        )
+        .unwrap();
+
+    // curve of config doesn't need curve variants
+    let mut pool_config = config;
+    apply_curve_variant_to_config(&config_key, &mut pool_config, 0, None).unwrap();
+    assert_eq!(
+        pool_config.migration_quote_threshold,
+        config.migration_quote_threshold
+    );
+
+    let mut pool_config = config;
+    apply_curve_variant_to_config(&config_key, &mut pool_config, 1, Some(&curve_variants)).unwrap();
+    assert_eq!(pool_config.migration_quote_threshold, 10_000_000_000);
+
+    // curve variants is required and must belong to the config
+    let mut pool_config = config;
+    assert!(apply_curve_variant_to_config(&config_key, &mut pool_config, 1, None).is_err());
+    assert!(apply_curve_variant_to_config(
+        &Pubkey::new_unique(),
+        &mut pool_config,
+        1,
+        Some(&curve_variants)
+    )
+    .is_err());
+}
+
+#[test]
+fn test_invalid_curve_variants() {
+    let config = get_config();
+
+    let mut curve_variants = CurveVariants::zeroed();
+    assert!(curve_variants
+        .initialize(Default::default(), &config, &[])
+        .is_err());
+
+    let too_many_variants = vec![get_variant_params(-200, 10_000_000_000); 5];
+    assert!(curve_variants
+        .initialize(Default::default(), &config, &too_many_variants)
+        .is_err());
+
+    // migration quote threshold must not be below minimum threshold of migration deadline
+    let config_with_deadline = PoolConfig {
+        min_migration_quote_threshold: 20_000_000_000,
+        migration_deadline_duration: 3600,
+        ..get_config()
+    };
+    let mut curve_variants = CurveVariants::zeroed();
+    assert!(curve_variants
+        .initialize(
+            Default::default(),
+            &config_with_deadline,
+            &[get_variant_params(-200, 10_000_000_000)],
+        )
+        .is_err());
+
+    // fixed token supply of config must cover every curve variant
+    let fixed_supply_config = PoolConfig {
+        fixed_token_supply_flag: 1,
+        pre_migration_token_supply: 1_000,
+        post_migration_token_supply: 1_000,
+        ..get_config()
+    };
+    let mut curve_variants = CurveVariants::zeroed();
+    assert!(curve_variants
+        .initialize(
+            Default::default(),
+            &fixed_supply_config,
+            &[get_variant_params(-200, 10_000_000_000)],
+        )
+        .is_err());
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+use crate::state::{DelegatedClaimFeeOperator, OperatorScope};
+
+#[test]
+fn test_delegated_operator_scope() {
+    let owner = Pubkey::new_unique();
+    let operator = Pubkey::new_unique();
+    let mut delegated_operator = DelegatedClaimFeeOperator::zeroed();
+    delegated_operator.initialize(owner, operator, OperatorScope::Partner);
+
+    assert!(delegated_operator.is_delegated(&owner, OperatorScope::Partner, &operator));
+    // partner scoped operator cannot claim creator fees
+    assert!(!delegated_operator.is_delegated(&owner, OperatorScope::Creator, &operator));
+    assert!(!delegated_operator.is_delegated(
+        &Pubkey::new_unique(),
+        OperatorScope::Partner,
+        &operator
+    ));
+    assert!(!delegated_operator.is_delegated(
+        &owner,
+        OperatorScope::Partner,
+        &Pubkey::new_unique()
+    ));
+}
@@ -0,0 +1,208 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program_option::COption, program_pack::Pack},
+};
+use anchor_spl::{
+    token::{spl_token, Token},
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            metadata_pointer::MetadataPointer, permanent_delegate::PermanentDelegate,
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        },
+    },
+    token_interface::{Mint, Token2022},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    state::{PoolConfig, PoolType, TokenType},
+    token::is_supported_base_mint,
+    validate_existing_base_mint, PoolError,
+};
+
+const DECIMALS: u8 = 6;
+
+fn get_mint_account(owner: Pubkey, data: Vec<u8>) -> InterfaceAccount<'static, Mint> {
+    let account_info = Box::leak(Box::new(AccountInfo::new(
+        Box::leak(Box::new(Pubkey::new_unique())),
+        false,
+        false,
+        Box::leak(Box::new(1_000_000_000)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )));
+    InterfaceAccount::try_from(account_info).unwrap()
+}
+
+fn get_spl_mint(freeze_authority: COption<Pubkey>) -> InterfaceAccount<'static, Mint> {
+    let mint = spl_token::state::Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply: 1_000_000,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority,
+    };
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint, &mut data).unwrap();
+    get_mint_account(Token::id(), data)
+}
+
+/// token 2022 mint with metadata extensions, and permanent delegate if it is set
+fn get_token_2022_mint(permanent_delegate: Option<Pubkey>) -> InterfaceAccount<'static, Mint> {
+    let mut extension_types = vec![ExtensionType::MetadataPointer];
+    if permanent_delegate.is_some() {
+        extension_types.push(ExtensionType::PermanentDelegate);
+    }
+    let token_metadata = TokenMetadata {
+        name: "name".to_string(),
+        symbol: "symbol".to_string(),
+        uri: "uri".to_string(),
+        ..Default::default()
+    };
+    let account_len =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extension_types)
+            .unwrap()
+            + token_metadata.tlv_size_of().unwrap();
+
+    let mut data = vec![0; account_len];
+    let mut mint =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .unwrap();
+    mint.init_extension::<MetadataPointer>(true).unwrap();
+    if let Some(permanent_delegate) = permanent_delegate {
+        mint.init_extension::<PermanentDelegate>(true)
+            .unwrap()
+            .delegate = Some(permanent_delegate).try_into().unwrap();
+    }
+    mint.base = spl_token_2022::state::Mint {
+        mint_authority: COption::Some(Pubkey::new_unique()),
+        supply: 1_000_000,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    mint.pack_base();
+    mint.init_account_type().unwrap();
+    mint.init_variable_len_extension(&token_metadata, false)
+        .unwrap();
+    get_mint_account(Token2022::id(), data)
+}
+
+fn get_config(token_type: TokenType) -> PoolConfig {
+    PoolConfig {
+        token_type: token_type.into(),
+        token_decimal: DECIMALS,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_is_supported_base_mint() {
+    assert!(is_supported_base_mint(&get_spl_mint(COption::None)).unwrap());
+    // metadata extensions only
+    assert!(is_supported_base_mint(&get_token_2022_mint(None)).unwrap());
+
+    // freeze authority could freeze holders
+    assert!(!is_supported_base_mint(&get_spl_mint(COption::Some(Pubkey::new_unique()))).unwrap());
+    // permanent delegate could move tokens out of the vault
+    assert!(!is_supported_base_mint(&get_token_2022_mint(Some(Pubkey::new_unique()))).unwrap());
+}
+
+#[test]
+fn test_validate_existing_base_mint() {
+    let quote_mint = Pubkey::new_unique();
+
+    let pool_type = validate_existing_base_mint(
+        &get_config(TokenType::SplToken),
+        &get_spl_mint(COption::None),
+        &quote_mint,
+    )
+    .unwrap();
+    assert_eq!(pool_type, PoolType::SplToken);
+
+    let pool_type = validate_existing_base_mint(
+        &get_config(TokenType::Token2022),
+        &get_token_2022_mint(None),
+        &quote_mint,
+    )
+    .unwrap();
+    assert_eq!(pool_type, PoolType::Token2022);
+}
+
+#[test]
+fn test_validate_existing_base_mint_rejections() {
+    let quote_mint = Pubkey::new_unique();
+
+    // token program of base mint must match token type of config
+    assert_eq!(
+        validate_existing_base_mint(
+            &get_config(TokenType::Token2022),
+            &get_spl_mint(COption::None),
+            &quote_mint,
+        )
+        .unwrap_err(),
+        PoolError::InvalidTokenType.into()
+    );
+    assert_eq!(
+        validate_existing_base_mint(
+            &get_config(TokenType::SplToken),
+            &get_token_2022_mint(None),
+            &quote_mint,
+        )
+        .unwrap_err(),
+        PoolError::InvalidTokenType.into()
+    );
+
+    let wrong_decimals_config = PoolConfig {
+        token_decimal: DECIMALS + 1,
+        ..get_config(TokenType::SplToken)
+    };
+    assert_eq!(
+        validate_existing_base_mint(
+            &wrong_decimals_config,
+            &get_spl_mint(COption::None),
+            &quote_mint,
+        )
+        .unwrap_err(),
+        PoolError::InvalidTokenDecimals.into()
+    );
+
+    assert_eq!(
+        validate_existing_base_mint(
+            &get_config(TokenType::SplToken),
+            &get_spl_mint(COption::Some(Pubkey::new_unique())),
+            &quote_mint,
+        )
+        .unwrap_err(),
+        PoolError::InvalidBaseMint.into()
+    );
+
+    // base mint can't be the quote mint
+    let base_mint = get_spl_mint(COption::None);
+    assert_eq!(
+        validate_existing_base_mint(
+            &get_config(TokenType::SplToken),
+            &base_mint,
+            &base_mint.key(),
+        )
+        .unwrap_err(),
+        PoolError::InvalidBaseMint.into()
+    );
+
+    let non_transferable_config = PoolConfig {
+        non_transferable_flag: 1,
+        ..get_config(TokenType::SplToken)
+    };
+    assert_eq!(
+        validate_existing_base_mint(
+            &non_transferable_config,
+            &get_spl_mint(COption::None),
+            &quote_mint,
+        )
+        .unwrap_err(),
+        PoolError::InvalidNonTransferableOption.into()
+    );
+}
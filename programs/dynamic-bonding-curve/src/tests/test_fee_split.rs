@@ -0,0 +1,112 @@
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+use crate::{
+    state::{validate_fee_split_recipients, CreatorFeeSplit, FeeSplit},
+    FeeSplitRecipientParams,
+};
+
+fn recipient(weight_bps: u16) -> FeeSplitRecipientParams {
+    FeeSplitRecipientParams {
+        recipient: Pubkey::new_unique(),
+        weight_bps,
+    }
+}
+
+#[test]
+fn test_validate_fee_split_recipients() {
+    assert!(validate_fee_split_recipients(&[recipient(6000), recipient(4000)]).is_ok());
+
+    // empty
+    assert!(validate_fee_split_recipients(&[]).is_err());
+    // weights don't sum up to 100%
+    assert!(validate_fee_split_recipients(&[recipient(6000), recipient(3000)]).is_err());
+    // zero weight
+    assert!(validate_fee_split_recipients(&[recipient(10000), recipient(0)]).is_err());
+    // duplicated recipient
+    let duplicated = recipient(5000);
+    assert!(validate_fee_split_recipients(&[duplicated, duplicated]).is_err());
+    // too many recipients
+    assert!(validate_fee_split_recipients(&[recipient(1000); 10]).is_err());
+}
+
+#[test]
+fn test_fee_split_distribute_and_claim() {
+    let recipients = [recipient(3333), recipient(3333), recipient(3334)];
+    let mut fee_split = FeeSplit::zeroed();
+    fee_split
+        .initialize(Pubkey::new_unique(), Pubkey::new_unique(), &recipients)
+        .unwrap();
+
+    fee_split.distribute(1_000, 0).unwrap();
+    fee_split.distribute(10, 100).unwrap();
+
+    let pending_amounts: Vec<u64> = fee_split.recipients[..3]
+        .iter()
+        .map(|r| r.pending_amount)
+        .collect();
+    // rounding dust goes to the last recipient
+    assert_eq!(pending_amounts, vec![336, 336, 338]);
+
+    let pending_sol_amounts: Vec<u64> = fee_split.recipients[..3]
+        .iter()
+        .map(|r| r.pending_sol_amount)
+        .collect();
+    assert_eq!(pending_sol_amounts, vec![33, 33, 34]);
+
+    assert_eq!(
+        fee_split.claim(&recipients[0].recipient).unwrap(),
+        (336, 33)
+    );
+    assert_eq!(fee_split.claim(&recipients[0].recipient).unwrap(), (0, 0));
+    assert_eq!(fee_split.recipients[0].total_claimed_amount, 336);
+    assert_eq!(fee_split.recipients[0].total_claimed_sol_amount, 33);
+    // other recipients are not affected
+    assert_eq!(fee_split.recipients[1].pending_amount, 336);
+    assert_eq!(fee_split.recipients[1].pending_sol_amount, 33);
+
+    assert!(fee_split.claim(&Pubkey::new_unique()).is_err());
+
+    // fee split can't be closed until every recipient claimed
+    assert!(!fee_split.is_fully_claimed());
+    fee_split.claim(&recipients[1].recipient).unwrap();
+    fee_split.claim(&recipients[2].recipient).unwrap();
+    assert!(fee_split.is_fully_claimed());
+}
+
+#[test]
+fn test_creator_fee_split_distribute_and_claim() {
+    let co_creators = [recipient(7000), recipient(3000)];
+    let mut creator_fee_split = CreatorFeeSplit::zeroed();
+    creator_fee_split
+        .initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &co_creators,
+        )
+        .unwrap();
+
+    creator_fee_split.distribute(1_001, 99).unwrap();
+    // surplus is only in quote token
+    creator_fee_split.distribute(0, 1_000).unwrap();
+
+    assert_eq!(
+        creator_fee_split.claim(&co_creators[0].recipient).unwrap(),
+        (700, 769)
+    );
+    assert_eq!(
+        creator_fee_split.claim(&co_creators[1].recipient).unwrap(),
+        (301, 330)
+    );
+    assert_eq!(
+        creator_fee_split.co_creators[1].total_claimed_base_amount,
+        301
+    );
+    assert_eq!(
+        creator_fee_split.claim(&co_creators[1].recipient).unwrap(),
+        (0, 0)
+    );
+
+    assert!(creator_fee_split.claim(&Pubkey::new_unique()).is_err());
+}
@@ -0,0 +1,69 @@
+use crate::state::{MigrationOption, MigrationProgress, PoolConfig, VirtualPool};
+
+fn get_config() -> PoolConfig {
+    PoolConfig {
+        migration_option: MigrationOption::DammV2.into(),
+        migration_quote_threshold: 1_000,
+        migration_base_threshold: 500,
+        migration_sqrt_price: 1u128 << 64,
+        min_migration_quote_threshold: 400,
+        migration_deadline_duration: 100,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_complete_curve_by_deadline() {
+    let config = get_config();
+    let mut pool = VirtualPool {
+        activation_point: 1_000,
+        quote_reserve: 300,
+        sqrt_price: 1u128 << 63,
+        ..Default::default()
+    };
+
+    // deadline is not reached
+    assert!(!pool.is_migration_deadline_reached(&config, 1_099).unwrap());
+    assert!(pool.is_migration_deadline_reached(&config, 1_100).unwrap());
+
+    // minimum quote amount is not raised
+    assert!(!pool.can_complete_curve_by_deadline(&config, 1_100).unwrap());
+    assert!(!pool.is_migration_deadline_missed(&config, 1_099).unwrap());
+    assert!(pool.is_migration_deadline_missed(&config, 1_100).unwrap());
+
+    pool.quote_reserve = 400;
+    assert!(pool.can_complete_curve_by_deadline(&config, 1_100).unwrap());
+    assert!(!pool.is_migration_deadline_missed(&config, 1_100).unwrap());
+
+    // before curve is completed, migration follows config
+    assert_eq!(pool.get_migration_quote_threshold(&config), 1_000);
+    assert_eq!(pool.get_migration_base_threshold(&config).unwrap(), 500);
+
+    pool.set_migration_progress(MigrationProgress::LockedVesting.into());
+    assert!(pool.is_curve_complete_by_deadline(&config));
+    assert!(pool.is_curve_complete(pool.get_migration_quote_threshold(&config)));
+    assert_eq!(pool.get_migration_quote_threshold(&config), 400);
+    assert_eq!(pool.get_migration_sqrt_price(&config), 1u128 << 63);
+    assert!(pool.get_migration_base_threshold(&config).unwrap() > 0);
+    assert_eq!(pool.get_total_surplus(400).unwrap(), 0);
+}
+
+#[test]
+fn test_no_migration_deadline() {
+    let config = PoolConfig {
+        min_migration_quote_threshold: 0,
+        migration_deadline_duration: 0,
+        ..get_config()
+    };
+    let pool = VirtualPool {
+        quote_reserve: 1_000,
+        ..Default::default()
+    };
+    assert!(!pool
+        .is_migration_deadline_reached(&config, u64::MAX)
+        .unwrap());
+    assert!(!pool
+        .can_complete_curve_by_deadline(&config, u64::MAX)
+        .unwrap());
+    assert_eq!(pool.get_migration_quote_threshold(&config), 1_000);
+}
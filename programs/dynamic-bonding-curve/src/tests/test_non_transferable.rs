@@ -0,0 +1,89 @@
+use crate::{
+    state::{CollectFeeMode, TokenAuthorityOption, VirtualPool},
+    validate_non_transferable_until_migration, LockedVestingParams,
+};
+
+#[test]
+fn test_frozen_account_count_through_swaps_and_thaws() {
+    let mut pool = VirtualPool {
+        non_transferable_flag: 1,
+        ..Default::default()
+    };
+    assert!(pool.is_non_transferable());
+
+    // first swap of each holder freezes its base token account
+    pool.track_frozen_account(false).unwrap();
+    pool.track_frozen_account(false).unwrap();
+    assert_eq!(pool.frozen_account_count, 2);
+
+    // next swaps of a frozen holder are not counted again
+    pool.track_frozen_account(true).unwrap();
+    assert_eq!(pool.frozen_account_count, 2);
+
+    // migration keeps freeze authority while any holder is frozen
+    assert!(pool.has_frozen_account());
+
+    // thaw after migration uncounts frozen accounts only
+    pool.untrack_frozen_account(true).unwrap();
+    assert_eq!(pool.frozen_account_count, 1);
+    pool.untrack_frozen_account(false).unwrap();
+    assert_eq!(pool.frozen_account_count, 1);
+    pool.untrack_frozen_account(true).unwrap();
+    assert!(!pool.has_frozen_account());
+
+    // can't thaw more accounts than swaps froze
+    assert!(pool.untrack_frozen_account(true).is_err());
+}
+
+#[test]
+fn test_validate_non_transferable_until_migration() {
+    let quote_token = u8::from(CollectFeeMode::QuoteToken);
+    let immutable = u8::from(TokenAuthorityOption::Immutable);
+    let no_vesting = LockedVestingParams::default();
+
+    assert!(
+        validate_non_transferable_until_migration(1, quote_token, immutable, &no_vesting).is_ok()
+    );
+
+    // invalid option
+    assert!(
+        validate_non_transferable_until_migration(2, quote_token, immutable, &no_vesting).is_err()
+    );
+
+    // base token trading fee could be transferred before migration
+    assert!(validate_non_transferable_until_migration(
+        1,
+        u8::from(CollectFeeMode::OutputToken),
+        immutable,
+        &no_vesting
+    )
+    .is_err());
+
+    // mint authority could mint to accounts that are never frozen
+    for token_authority in [
+        TokenAuthorityOption::CreatorUpdateAndMintAuthority,
+        TokenAuthorityOption::PartnerUpdateAndMintAuthority,
+    ] {
+        assert!(validate_non_transferable_until_migration(
+            1,
+            quote_token,
+            u8::from(token_authority),
+            &no_vesting
+        )
+        .is_err());
+    }
+
+    // locked vesting escrow is never frozen and could be claimed before migration without cliff
+    let locked_vesting = LockedVestingParams {
+        amount_per_period: 1_000,
+        cliff_duration_from_migration_time: 0,
+        frequency: 1,
+        number_of_period: 10,
+        cliff_unlock_amount: 0,
+    };
+    assert!(locked_vesting.validate().is_ok());
+    assert!(
+        validate_non_transferable_until_migration(1, quote_token, immutable, &locked_vesting)
+            .is_err()
+    );
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+
+use crate::{
+    ed25519::is_ed25519_signed_message, get_pool_creation_approval_message, state::PoolConfig,
+    POOL_CREATION_APPROVAL_DOMAIN,
+};
+
+/// build ed25519 instruction data the same way as solana sdk, signature is not checked by the program
+fn new_ed25519_instruction(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Instruction {
+    let public_key_offset: u16 = 16;
+    let signature_offset: u16 = public_key_offset + 32;
+    let message_data_offset: u16 = signature_offset + 64;
+
+    let mut data = vec![1u8, 0];
+    for value in [
+        signature_offset,
+        instruction_index,
+        public_key_offset,
+        instruction_index,
+        message_data_offset,
+        message.len() as u16,
+        instruction_index,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(&[0u8; 64]);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[test]
+fn test_pool_creation_approval_message() {
+    let authority = Pubkey::new_unique();
+    let config = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let base_mint = Pubkey::new_unique();
+    let message = get_pool_creation_approval_message(&config, &creator, &base_mint);
+    // message is bound to the purpose and the program
+    assert!(message.starts_with(POOL_CREATION_APPROVAL_DOMAIN));
+    assert_eq!(
+        &message[POOL_CREATION_APPROVAL_DOMAIN.len()..POOL_CREATION_APPROVAL_DOMAIN.len() + 32],
+        crate::ID.as_ref()
+    );
+    // signature over the previous message without domain separator is rejected
+    let legacy_message = [config.as_ref(), creator.as_ref(), base_mint.as_ref()].concat();
+    let instruction = new_ed25519_instruction(&authority, &legacy_message, u16::MAX);
+    assert!(!is_ed25519_signed_message(
+        &instruction,
+        &authority,
+        &message
+    ));
+
+    let instruction = new_ed25519_instruction(&authority, &message, u16::MAX);
+    assert!(is_ed25519_signed_message(
+        &instruction,
+        &authority,
+        &message
+    ));
+
+    // signed by other key
+    assert!(!is_ed25519_signed_message(
+        &instruction,
+        &Pubkey::new_unique(),
+        &message
+    ));
+
+    // approval for other base mint
+    let other_message =
+        get_pool_creation_approval_message(&config, &creator, &Pubkey::new_unique());
+    assert!(!is_ed25519_signed_message(
+        &instruction,
+        &authority,
+        &other_message
+    ));
+
+    // data must be in the ed25519 instruction itself
+    let instruction = new_ed25519_instruction(&authority, &message, 0);
+    assert!(!is_ed25519_signed_message(
+        &instruction,
+        &authority,
+        &message
+    ));
+
+    // not ed25519 program
+    let mut instruction = new_ed25519_instruction(&authority, &message, u16::MAX);
+    instruction.program_id = Pubkey::new_unique();
+    assert!(!is_ed25519_signed_message(
+        &instruction,
+        &authority,
+        &message
+    ));
+}
+
+#[test]
+fn test_pool_creator_authority_in_config() {
+    let mut config = PoolConfig::default();
+    assert!(!config.has_pool_creator_authority());
+
+    config.pool_creator_authority = Pubkey::new_unique();
+    assert!(config.has_pool_creator_authority());
+
+    // default pubkey opens pool creation to anyone
+    config.pool_creator_authority = Pubkey::default();
+    assert!(!config.has_pool_creator_authority());
+}
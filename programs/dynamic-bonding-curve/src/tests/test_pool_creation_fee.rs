@@ -0,0 +1,59 @@
+use crate::state::{
+    PoolConfig, PoolCreationFeeSplit, PoolCreationFeeToken, PoolFeesConfig, VirtualPool,
+};
+
+#[test]
+fn test_split_pool_creation_fee() {
+    let config = PoolConfig {
+        pool_fees: PoolFeesConfig {
+            pool_creation_fee: 1_000_001,
+            pool_creation_fee_token: PoolCreationFeeToken::QuoteToken.into(),
+            pool_creation_fee_protocol_percent: 20,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        config.get_pool_creation_fee_token().unwrap(),
+        PoolCreationFeeToken::QuoteToken
+    );
+
+    let PoolCreationFeeSplit {
+        partner_fee,
+        protocol_fee,
+    } = config.split_pool_creation_fee().unwrap();
+    // rounding goes to partner
+    assert_eq!(protocol_fee, 200_000);
+    assert_eq!(partner_fee, 800_001);
+
+    // pool creation fee in quote token is claimed with trading fees
+    let mut pool = VirtualPool::default();
+    pool.accumulate_pool_creation_fee(partner_fee, protocol_fee)
+        .unwrap();
+    assert_eq!(
+        pool.claim_partner_trading_fee(0, u64::MAX).unwrap(),
+        (0, 800_001)
+    );
+    assert_eq!(pool.claim_protocol_fee(), (0, 200_000));
+}
+
+#[test]
+fn test_split_pool_creation_fee_without_protocol_cut() {
+    let config = PoolConfig {
+        pool_fees: PoolFeesConfig {
+            pool_creation_fee: 5_000,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        config.get_pool_creation_fee_token().unwrap(),
+        PoolCreationFeeToken::Sol
+    );
+    let PoolCreationFeeSplit {
+        partner_fee,
+        protocol_fee,
+    } = config.split_pool_creation_fee().unwrap();
+    assert_eq!(partner_fee, 5_000);
+    assert_eq!(protocol_fee, 0);
+}
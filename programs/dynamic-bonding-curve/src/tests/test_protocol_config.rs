@@ -0,0 +1,153 @@
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+use crate::{constants::MAX_PROTOCOL_ADMIN, state::ProtocolConfig};
+
+#[test]
+fn test_add_and_rotate_admin() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    let admin = Pubkey::new_unique();
+    protocol_config.initialize(admin);
+    assert!(protocol_config.is_admin(&admin));
+    assert!(!protocol_config.is_admin(&Pubkey::default()));
+
+    // add
+    let new_admin = Pubkey::new_unique();
+    protocol_config
+        .propose_admin(admin, new_admin, Pubkey::default())
+        .unwrap();
+    assert!(!protocol_config.is_admin(&new_admin));
+    protocol_config.accept_admin().unwrap();
+    assert!(protocol_config.is_admin(&new_admin));
+    assert_eq!(protocol_config.get_admin_count(), 2);
+    assert!(!protocol_config.has_pending_admin());
+
+    // cannot propose an existing admin
+    assert!(protocol_config
+        .propose_admin(admin, new_admin, Pubkey::default())
+        .is_err());
+
+    // rotate
+    let rotated_admin = Pubkey::new_unique();
+    protocol_config
+        .propose_admin(admin, rotated_admin, admin)
+        .unwrap();
+    protocol_config.accept_admin().unwrap();
+    assert!(protocol_config.is_admin(&rotated_admin));
+    assert!(!protocol_config.is_admin(&admin));
+    assert_eq!(protocol_config.get_admin_count(), 2);
+}
+
+#[test]
+fn test_remove_admin() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    let admin = Pubkey::new_unique();
+    protocol_config.initialize(admin);
+
+    // cannot remove the last admin
+    assert!(protocol_config.propose_remove_admin(admin, admin).is_err());
+
+    let new_admin = Pubkey::new_unique();
+    protocol_config
+        .propose_admin(admin, new_admin, Pubkey::default())
+        .unwrap();
+    protocol_config.accept_admin().unwrap();
+
+    // removal must be confirmed by another admin
+    protocol_config
+        .propose_remove_admin(new_admin, admin)
+        .unwrap();
+    assert!(protocol_config.is_admin(&admin));
+    assert!(protocol_config.confirm_remove_admin(new_admin).is_err());
+    assert!(protocol_config
+        .confirm_remove_admin(Pubkey::new_unique())
+        .is_err());
+
+    // pending rotation of the removed admin is cleared
+    protocol_config
+        .propose_admin(admin, Pubkey::new_unique(), admin)
+        .unwrap();
+    assert_eq!(protocol_config.confirm_remove_admin(admin).unwrap(), admin);
+    assert!(!protocol_config.is_admin(&admin));
+    assert!(!protocol_config.has_pending_admin());
+    assert!(!protocol_config.has_pending_removed_admin());
+
+    // nothing to confirm
+    assert!(protocol_config.confirm_remove_admin(new_admin).is_err());
+    assert!(protocol_config
+        .propose_remove_admin(new_admin, Pubkey::default())
+        .is_err());
+}
+
+#[test]
+fn test_rotate_admin_clears_pending_removal() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    let admin = Pubkey::new_unique();
+    protocol_config.initialize(admin);
+    let second_admin = Pubkey::new_unique();
+    protocol_config
+        .propose_admin(admin, second_admin, Pubkey::default())
+        .unwrap();
+    protocol_config.accept_admin().unwrap();
+
+    // proposer is rotated out before the removal is confirmed
+    protocol_config
+        .propose_remove_admin(admin, second_admin)
+        .unwrap();
+    protocol_config
+        .propose_admin(admin, Pubkey::new_unique(), admin)
+        .unwrap();
+    protocol_config.accept_admin().unwrap();
+    assert!(!protocol_config.has_pending_removed_admin());
+    assert!(protocol_config.confirm_remove_admin(second_admin).is_err());
+    assert_eq!(protocol_config.get_admin_count(), 2);
+}
+
+#[test]
+fn test_max_admin() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    let admin = Pubkey::new_unique();
+    protocol_config.initialize(admin);
+    for _ in 1..MAX_PROTOCOL_ADMIN {
+        protocol_config
+            .propose_admin(admin, Pubkey::new_unique(), Pubkey::default())
+            .unwrap();
+        protocol_config.accept_admin().unwrap();
+    }
+    assert!(protocol_config
+        .propose_admin(admin, Pubkey::new_unique(), Pubkey::default())
+        .is_err());
+}
+
+#[test]
+fn test_single_admin_cannot_replace_another_admin() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    let admin = Pubkey::new_unique();
+    protocol_config.initialize(admin);
+    let victim = Pubkey::new_unique();
+    protocol_config
+        .propose_admin(admin, victim, Pubkey::default())
+        .unwrap();
+    protocol_config.accept_admin().unwrap();
+
+    // admin proposes a key it controls to replace another admin
+    let controlled_key = Pubkey::new_unique();
+    assert!(protocol_config
+        .propose_admin(admin, controlled_key, victim)
+        .is_err());
+    // non admin can't propose
+    assert!(protocol_config
+        .propose_admin(controlled_key, Pubkey::new_unique(), Pubkey::default())
+        .is_err());
+
+    // pending admin proposed by a removed admin can't be accepted
+    protocol_config
+        .propose_admin(admin, controlled_key, Pubkey::default())
+        .unwrap();
+    protocol_config.propose_remove_admin(victim, admin).unwrap();
+    protocol_config.confirm_remove_admin(admin).unwrap();
+    assert!(!protocol_config.has_pending_admin());
+    assert!(protocol_config.accept_admin().is_err());
+    assert!(protocol_config.is_admin(&victim));
+    assert!(!protocol_config.is_admin(&controlled_key));
+}
@@ -0,0 +1,91 @@
+use bytemuck::Zeroable;
+
+use crate::state::{PoolConfig, ProtocolConfig, VirtualPool};
+
+#[test]
+fn test_default_surplus_share() {
+    // surplus is split by creator trading fee percentage
+    let config = PoolConfig {
+        creator_trading_fee_percentage: 25,
+        ..Default::default()
+    };
+    let pool = VirtualPool {
+        quote_reserve: 2_000,
+        ..Default::default()
+    };
+    let total_surplus = pool.get_total_surplus(1_000).unwrap();
+
+    assert_eq!(
+        pool.get_partner_surplus(&config, total_surplus).unwrap(),
+        600
+    );
+    assert_eq!(
+        pool.get_creator_surplus(&config, total_surplus).unwrap(),
+        200
+    );
+    assert_eq!(pool.get_protocol_surplus(&config, 1_000).unwrap(), 200);
+}
+
+#[test]
+fn test_configurable_surplus_share() {
+    // surplus split is independent from creator trading fee percentage
+    let config = PoolConfig {
+        creator_trading_fee_percentage: 25,
+        partner_and_creator_surplus_share: 90,
+        creator_surplus_percentage: 50,
+        ..Default::default()
+    };
+    let pool = VirtualPool {
+        quote_reserve: 2_000,
+        ..Default::default()
+    };
+    let total_surplus = pool.get_total_surplus(1_000).unwrap();
+
+    assert_eq!(
+        pool.get_partner_surplus(&config, total_surplus).unwrap(),
+        450
+    );
+    assert_eq!(
+        pool.get_creator_surplus(&config, total_surplus).unwrap(),
+        450
+    );
+    assert_eq!(pool.get_protocol_surplus(&config, 1_000).unwrap(), 100);
+}
+
+#[test]
+fn test_surplus_share_tiers() {
+    let mut protocol_config = ProtocolConfig::zeroed();
+    assert!(!protocol_config.is_approved_surplus_share(90));
+
+    protocol_config
+        .update_surplus_share_tiers([80, 90, 0, 0, 0, 0, 0, 0])
+        .unwrap();
+    assert!(protocol_config.is_approved_surplus_share(90));
+    assert!(!protocol_config.is_approved_surplus_share(70));
+    // empty slot is never approved
+    assert!(!protocol_config.is_approved_surplus_share(0));
+
+    assert!(protocol_config
+        .update_surplus_share_tiers([101, 0, 0, 0, 0, 0, 0, 0])
+        .is_err());
+}
+
+#[test]
+fn test_surplus_distribution_covers_total_surplus() {
+    let config = PoolConfig {
+        partner_and_creator_surplus_share: 85,
+        creator_surplus_percentage: 33,
+        ..Default::default()
+    };
+    let pool = VirtualPool {
+        quote_reserve: 1_999,
+        ..Default::default()
+    };
+    let total_surplus = pool.get_total_surplus(1_000).unwrap();
+
+    // rounding never pays more than total surplus
+    let distributed_surplus = pool.get_partner_surplus(&config, total_surplus).unwrap()
+        + pool.get_creator_surplus(&config, total_surplus).unwrap()
+        + pool.get_protocol_surplus(&config, 1_000).unwrap();
+    assert_eq!(distributed_surplus, total_surplus);
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+use crate::{
+    state::TokenMetadataConfig, TokenMetadataConfigParameters, TokenMetadataCreatorParameters,
+};
+
+fn get_params(creators: &[(Pubkey, u8)]) -> TokenMetadataConfigParameters {
+    TokenMetadataConfigParameters {
+        seller_fee_basis_points: 500,
+        is_mutable: true,
+        collection: None,
+        creators: creators
+            .iter()
+            .map(|(address, share)| TokenMetadataCreatorParameters {
+                address: *address,
+                share: *share,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_token_metadata_config_creators() {
+    let partner = Pubkey::new_unique();
+    let pool_creator = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+
+    let mut token_metadata_config = TokenMetadataConfig::zeroed();
+    token_metadata_config
+        .initialize(
+            Default::default(),
+            &TokenMetadataConfigParameters {
+                collection: Some(collection),
+                ..get_params(&[(partner, 30), (Pubkey::default(), 70)])
+            },
+        )
+        .unwrap();
+
+    // default pubkey is replaced by pool creator
+    assert_eq!(
+        token_metadata_config.get_creators(pool_creator),
+        vec![(partner, 30), (pool_creator, 70)]
+    );
+    assert_eq!(token_metadata_config.get_collection(), Some(collection));
+    assert!(token_metadata_config.is_mutable());
+
+    assert_eq!(
+        token_metadata_config.get_additional_metadata(pool_creator),
+        vec![
+            ("seller_fee_basis_points".to_string(), "500".to_string()),
+            (
+                "creators".to_string(),
+                format!("{partner}:30,{pool_creator}:70")
+            ),
+            ("collection".to_string(), collection.to_string()),
+        ]
+    );
+
+    // no creator and no collection
+    let mut token_metadata_config = TokenMetadataConfig::zeroed();
+    token_metadata_config
+        .initialize(Default::default(), &get_params(&[]))
+        .unwrap();
+    assert!(token_metadata_config.get_creators(pool_creator).is_empty());
+    assert_eq!(token_metadata_config.get_collection(), None);
+    assert_eq!(
+        token_metadata_config.get_additional_metadata(pool_creator),
+        vec![("seller_fee_basis_points".to_string(), "500".to_string())]
+    );
+}
+
+#[test]
+fn test_invalid_token_metadata_config() {
+    let first_creator = Pubkey::new_unique();
+    let second_creator = Pubkey::new_unique();
+
+    // shares must sum up to 100
+    assert!(get_params(&[(first_creator, 30), (second_creator, 60)])
+        .validate()
+        .is_err());
+
+    // creators must be unique
+    assert!(get_params(&[(first_creator, 30), (first_creator, 70)])
+        .validate()
+        .is_err());
+
+    // at most 5 creators
+    let too_many_creators: Vec<(Pubkey, u8)> = (0..6).map(|_| (Pubkey::new_unique(), 0)).collect();
+    assert!(get_params(&too_many_creators).validate().is_err());
+
+    // royalties can't exceed 100%
+    assert!(TokenMetadataConfigParameters {
+        seller_fee_basis_points: 10_001,
+        ..get_params(&[])
+    }
+    .validate()
+    .is_err());
+}
@@ -0,0 +1,16 @@
+use std::mem::{offset_of, size_of};
+
+use crate::{
+    constants::MAX_CURVE_POINT,
+    state::{LiquidityDistributionConfig, PoolConfig},
+};
+
+#[test]
+fn test_pending_fee_claimer_layout() {
+    // pending fee claimer takes curve points above MAX_CURVE_POINT, which configs never wrote
+    assert_eq!(
+        offset_of!(PoolConfig, pending_fee_claimer),
+        offset_of!(PoolConfig, curve) + MAX_CURVE_POINT * size_of::<LiquidityDistributionConfig>()
+    );
+    assert!(!PoolConfig::default().has_pending_fee_claimer());
+}
@@ -0,0 +1,61 @@
+use crate::state::PoolConfig;
+
+#[test]
+fn test_has_no_pool() {
+    let mut config = PoolConfig {
+        pool_count_flag: 1,
+        ..Default::default()
+    };
+    assert!(config.has_no_pool());
+
+    config.increase_pool_count().unwrap();
+    assert!(!config.has_no_pool());
+
+    // pool count of configs created before pool counting is unknown
+    let legacy_config = PoolConfig::default();
+    assert!(!legacy_config.has_no_pool());
+}
+
+#[test]
+fn test_is_same_for_existing_pools() {
+    let config = PoolConfig {
+        pool_count_flag: 1,
+        pool_count: 3,
+        migration_quote_threshold: 1_000,
+        ..Default::default()
+    };
+
+    let mut launch_only_update = config;
+    launch_only_update.token_decimal = 9;
+    launch_only_update.pool_fees.pool_creation_fee = 1_000_000;
+    launch_only_update.pool_fees.pool_creation_fee_token = 1;
+    launch_only_update
+        .pool_fees
+        .pool_creation_fee_protocol_percent = 10;
+    launch_only_update.pool_fees.base_transfer_fee_bps = 100;
+    launch_only_update.pool_fees.base_max_transfer_fee = 1_000;
+    launch_only_update.pool_fees.base_transfer_fee_authority = 1;
+    launch_only_update.non_transferable_flag = 1;
+    assert!(config.is_same_for_existing_pools(&launch_only_update));
+
+    // token type and token authority are read by existing pools to update token metadata
+    let mut token_type_update = config;
+    token_type_update.token_type = 1;
+    assert!(!config.is_same_for_existing_pools(&token_type_update));
+
+    let mut token_authority_update = config;
+    token_authority_update.token_update_authority = 1;
+    assert!(!config.is_same_for_existing_pools(&token_authority_update));
+
+    let mut threshold_update = config;
+    threshold_update.migration_quote_threshold = 2_000;
+    assert!(!config.is_same_for_existing_pools(&threshold_update));
+
+    let mut fee_update = config;
+    fee_update.pool_fees.base_fee.cliff_fee_numerator = 1;
+    assert!(!config.is_same_for_existing_pools(&fee_update));
+
+    let mut curve_update = config;
+    curve_update.curve[0].liquidity = 1;
+    assert!(!config.is_same_for_existing_pools(&curve_update));
+}
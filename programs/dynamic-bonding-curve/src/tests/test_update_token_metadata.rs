@@ -0,0 +1,91 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensionsMut, ExtensionType,
+        StateWithExtensionsMut,
+    },
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    get_token_2022_metadata_update_authority,
+    state::{PoolType, CREATORS_KEY},
+    TokenMetadataFieldParameters, UpdateTokenMetadataParameters,
+};
+
+/// token 2022 mint data with metadata stored in the mint
+fn get_mint_data(update_authority: Option<Pubkey>) -> Vec<u8> {
+    let token_metadata = TokenMetadata {
+        update_authority: update_authority.try_into().unwrap(),
+        name: "name".to_string(),
+        symbol: "symbol".to_string(),
+        uri: "uri".to_string(),
+        ..Default::default()
+    };
+    let account_len =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+            .unwrap()
+            + token_metadata.tlv_size_of().unwrap();
+    let mut data = vec![0; account_len];
+    let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    mint.init_extension::<MetadataPointer>(true).unwrap();
+    mint.base = Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    };
+    mint.pack_base();
+    mint.init_account_type().unwrap();
+    mint.init_variable_len_extension(&token_metadata, false)
+        .unwrap();
+    data
+}
+
+#[test]
+fn test_update_token_metadata_params() {
+    let params = UpdateTokenMetadataParameters {
+        name: Some("new name".to_string()),
+        uri: Some("https://new.uri".to_string()),
+        ..Default::default()
+    };
+    assert!(params.validate(PoolType::SplToken, true).is_ok());
+    assert!(params.validate(PoolType::Token2022, true).is_ok());
+
+    let params = UpdateTokenMetadataParameters {
+        additional_metadata: vec![TokenMetadataFieldParameters {
+            key: "website".to_string(),
+            value: "https://website".to_string(),
+        }],
+        ..Default::default()
+    };
+    // spl token metadata has no additional metadata
+    assert!(params.validate(PoolType::SplToken, false).is_err());
+    assert!(params.validate(PoolType::Token2022, true).is_ok());
+
+    // fields of token metadata config can't be overwritten
+    let params = UpdateTokenMetadataParameters {
+        additional_metadata: vec![TokenMetadataFieldParameters {
+            key: CREATORS_KEY.to_string(),
+            value: "creator:100".to_string(),
+        }],
+        ..Default::default()
+    };
+    assert!(params.validate(PoolType::Token2022, true).is_err());
+    assert!(params.validate(PoolType::Token2022, false).is_ok());
+}
+
+#[test]
+fn test_get_token_2022_metadata_update_authority() {
+    // signer is checked against the update authority stored in the metadata
+    let update_authority = Pubkey::new_unique();
+    assert_eq!(
+        get_token_2022_metadata_update_authority(&get_mint_data(Some(update_authority))).unwrap(),
+        Some(update_authority)
+    );
+    // no update authority means metadata is immutable
+    assert_eq!(
+        get_token_2022_metadata_update_authority(&get_mint_data(None)).unwrap(),
+        None
+    );
+}
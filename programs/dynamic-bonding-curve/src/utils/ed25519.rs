@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+
+/// size of header (number of signatures and padding) in ed25519 instruction data
+const ED25519_HEADER_SIZE: usize = 2;
+/// size of signature offsets struct in ed25519 instruction data
+const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+/// instruction index that refers to the ed25519 instruction itself
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Check whether the instruction is an ed25519 signature verification of `message` by `signer`.
+/// Only single signature instructions that carry pubkey, signature and message in their own data are accepted.
+pub fn is_ed25519_signed_message(
+    instruction: &Instruction,
+    signer: &Pubkey,
+    message: &[u8],
+) -> bool {
+    if instruction.program_id != ed25519_program::ID {
+        return false;
+    }
+    let data = &instruction.data;
+    if data.len() < ED25519_HEADER_SIZE + ED25519_SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+        return false;
+    }
+
+    let offsets = ED25519_HEADER_SIZE;
+    let (
+        Some(signature_instruction_index),
+        Some(public_key_offset),
+        Some(public_key_instruction_index),
+        Some(message_data_offset),
+        Some(message_data_size),
+        Some(message_instruction_index),
+    ) = (
+        read_u16(data, offsets + 2),
+        read_u16(data, offsets + 4),
+        read_u16(data, offsets + 6),
+        read_u16(data, offsets + 8),
+        read_u16(data, offsets + 10),
+        read_u16(data, offsets + 12),
+    )
+    else {
+        return false;
+    };
+
+    // pubkey, signature and message must be in the ed25519 instruction itself
+    if signature_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || public_key_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || message_instruction_index != CURRENT_INSTRUCTION_INDEX
+    {
+        return false;
+    }
+
+    let public_key_offset = usize::from(public_key_offset);
+    let message_data_offset = usize::from(message_data_offset);
+    let signed_pubkey = data.get(public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE);
+    let signed_message =
+        data.get(message_data_offset..message_data_offset + usize::from(message_data_size));
+
+    signed_pubkey == Some(signer.as_ref()) && signed_message == Some(message)
+}
@@ -1,2 +1,3 @@
 pub mod activation_handler;
+pub mod ed25519;
 pub mod token;
@@ -8,9 +8,17 @@ use anchor_spl::{
     token::Token,
     token_2022::spl_token_2022::{
         self,
-        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        extension::{
+            transfer_fee::{
+                TransferFee, TransferFeeAmount, TransferFeeConfig, MAX_FEE_BASIS_POINTS,
+            },
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        },
+    },
+    token_interface::{
+        harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint, Mint, TokenAccount,
+        TokenInterface,
     },
-    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
@@ -105,7 +113,260 @@ pub fn transfer_from_pool<'c: 'info, 'info>(
     Ok(())
 }
 
+/// freeze token account of a mint whose freeze authority is pool authority
+pub fn freeze_token_account<'info>(
+    pool_authority: AccountInfo<'info>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    bump: u8,
+) -> Result<()> {
+    let signer_seeds = pool_authority_seeds!(bump);
+
+    let instruction = spl_token_2022::instruction::freeze_account(
+        token_program.key,
+        &token_account.key(),
+        &token_mint.key(),
+        &pool_authority.key(),
+        &[],
+    )?;
+
+    let account_infos = vec![
+        token_account.to_account_info(),
+        token_mint.to_account_info(),
+        pool_authority.to_account_info(),
+    ];
+
+    invoke_signed(&instruction, &account_infos, &[&signer_seeds[..]])?;
+
+    Ok(())
+}
+
+/// remove freeze authority of a mint whose freeze authority is pool authority
+pub fn revoke_freeze_authority<'info>(
+    pool_authority: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    bump: u8,
+) -> Result<()> {
+    let signer_seeds = pool_authority_seeds!(bump);
+    anchor_spl::token_interface::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            anchor_spl::token_interface::SetAuthority {
+                current_authority: pool_authority,
+                account_or_mint: token_mint,
+            },
+            &[&signer_seeds[..]],
+        ),
+        spl_token_2022::instruction::AuthorityType::FreezeAccount,
+        None,
+    )
+}
+
+/// thaw token account of a mint whose freeze authority is pool authority, nothing is done if it isn't frozen
+pub fn thaw_token_account<'info>(
+    pool_authority: AccountInfo<'info>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    bump: u8,
+) -> Result<()> {
+    if !token_account.is_frozen() {
+        return Ok(());
+    }
+
+    let signer_seeds = pool_authority_seeds!(bump);
+
+    let instruction = spl_token_2022::instruction::thaw_account(
+        token_program.key,
+        &token_account.key(),
+        &token_mint.key(),
+        &pool_authority.key(),
+        &[],
+    )?;
+
+    let account_infos = vec![
+        token_account.to_account_info(),
+        token_mint.to_account_info(),
+        pool_authority.to_account_info(),
+    ];
+
+    invoke_signed(&instruction, &account_infos, &[&signer_seeds[..]])?;
+
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+pub struct TransferFeeExcludedAmount {
+    pub amount: u64,
+    pub transfer_fee: u64,
+}
+
+/// transfer fee of token 2022 mint in current epoch, none if mint has no transfer fee extension
+pub fn get_epoch_transfer_fee(mint_info: &AccountInfo) -> Result<Option<TransferFee>> {
+    if *mint_info.owner == Token::id() {
+        return Ok(None);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
+        let epoch = Clock::get()?.epoch;
+        return Ok(Some(*transfer_fee_config.get_epoch_fee(epoch)));
+    }
+    Ok(None)
+}
+
+/// amount received by destination account, transfer fee is withheld in destination account
+pub fn calculate_transfer_fee_excluded_amount(
+    transfer_fee: Option<&TransferFee>,
+    transfer_fee_included_amount: u64,
+) -> Result<TransferFeeExcludedAmount> {
+    let transfer_fee = match transfer_fee {
+        Some(transfer_fee) => transfer_fee
+            .calculate_fee(transfer_fee_included_amount)
+            .ok_or(PoolError::MathOverflow)?,
+        None => 0,
+    };
+    Ok(TransferFeeExcludedAmount {
+        amount: transfer_fee_included_amount.safe_sub(transfer_fee)?,
+        transfer_fee,
+    })
+}
+
+/// amount received by destination account when amounts are sent in separate transfers, each transfer pays its own transfer fee
+pub fn calculate_transfer_fee_excluded_amount_of_transfers(
+    transfer_fee: Option<&TransferFee>,
+    transfer_fee_included_amounts: &[u64],
+) -> Result<u64> {
+    let mut amount: u64 = 0;
+    for transfer_fee_included_amount in transfer_fee_included_amounts {
+        amount = amount.safe_add(
+            calculate_transfer_fee_excluded_amount(transfer_fee, *transfer_fee_included_amount)?
+                .amount,
+        )?;
+    }
+    Ok(amount)
+}
+
+#[derive(Debug, Default)]
+pub struct TransferFeeIncludedAmount {
+    pub amount: u64,
+    pub transfer_fee: u64,
+}
+
+/// amount source account must send, so destination account receives exactly the transfer fee excluded amount
+pub fn calculate_transfer_fee_included_amount(
+    transfer_fee: Option<&TransferFee>,
+    transfer_fee_excluded_amount: u64,
+) -> Result<TransferFeeIncludedAmount> {
+    let transfer_fee = match transfer_fee {
+        Some(transfer_fee) if transfer_fee_excluded_amount > 0 => {
+            if u16::from(transfer_fee.transfer_fee_basis_points) == MAX_FEE_BASIS_POINTS {
+                u64::from(transfer_fee.maximum_fee)
+            } else {
+                transfer_fee
+                    .calculate_inverse_fee(transfer_fee_excluded_amount)
+                    .ok_or(PoolError::MathOverflow)?
+            }
+        }
+        _ => 0,
+    };
+    Ok(TransferFeeIncludedAmount {
+        amount: transfer_fee_excluded_amount.safe_add(transfer_fee)?,
+        transfer_fee,
+    })
+}
+
+/// transfer fee withheld in token 2022 account, account can't be closed until it is harvested to the mint
+pub fn get_withheld_transfer_fee(token_account: &InterfaceAccount<TokenAccount>) -> Result<u64> {
+    let account_info = token_account.to_account_info();
+    if *account_info.owner == Token::id() {
+        return Ok(0);
+    }
+
+    let account_data = account_info.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account_data)?;
+    if let Ok(transfer_fee_amount) = account.get_extension::<TransferFeeAmount>() {
+        return Ok(transfer_fee_amount.withheld_amount.into());
+    }
+    Ok(0)
+}
+
+/// harvest transfer fee withheld in pool token account to the mint, so the account can be closed
+pub fn harvest_withheld_transfer_fee<'info>(
+    token_vault: &InterfaceAccount<'info, TokenAccount>,
+    token_mint: Option<&UncheckedAccount<'info>>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    if get_withheld_transfer_fee(token_vault)? == 0 {
+        return Ok(());
+    }
+    let token_mint = token_mint.ok_or(PoolError::InvalidAccount)?;
+    harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            token_program.to_account_info(),
+            HarvestWithheldTokensToMint {
+                token_program_id: token_program.to_account_info(),
+                mint: token_mint.to_account_info(),
+            },
+        ),
+        vec![token_vault.to_account_info()],
+    )
+}
+
+pub fn close_pool_token_account<'info>(
+    pool_authority: AccountInfo<'info>,
+    token_vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    bump: u8,
+) -> Result<()> {
+    let signer_seeds = pool_authority_seeds!(bump);
+
+    let instruction = spl_token_2022::instruction::close_account(
+        token_program.key,
+        &token_vault.key(),
+        destination.key,
+        &pool_authority.key(),
+        &[],
+    )?;
+
+    let account_infos = vec![token_vault.to_account_info(), destination, pool_authority];
+
+    invoke_signed(&instruction, &account_infos, &[&signer_seeds[..]])?;
+
+    Ok(())
+}
+
+/// token 2022 quote mint can have metadata and transfer fee extensions, swap and migration exclude transfer fee from the amount pool receives
 pub fn is_supported_quote_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
+    has_only_extensions(
+        mint_account,
+        &[
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenMetadata,
+            ExtensionType::TransferFeeConfig,
+        ],
+    )
+}
+
+/// Base mint of a pool created with an existing mint can't be frozen, and only metadata extensions are allowed for token 2022
+pub fn is_supported_base_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
+    if mint_account.freeze_authority.is_some() {
+        return Ok(false);
+    }
+    has_only_extensions(
+        mint_account,
+        &[ExtensionType::MetadataPointer, ExtensionType::TokenMetadata],
+    )
+}
+
+fn has_only_extensions(
+    mint_account: &InterfaceAccount<Mint>,
+    supported_extensions: &[ExtensionType],
+) -> Result<bool> {
     let mint_info = mint_account.to_account_info();
     if *mint_info.owner == Token::id() {
         return Ok(true);
@@ -119,7 +380,7 @@ pub fn is_supported_quote_mint(mint_account: &InterfaceAccount<Mint>) -> Result<
     let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
     let extensions = mint.get_extension_types()?;
     for e in extensions {
-        if e != ExtensionType::MetadataPointer && e != ExtensionType::TokenMetadata {
+        if !supported_extensions.contains(&e) {
             return Ok(false);
         }
     }
@@ -111,6 +111,20 @@ describe("Fee Swap test", () => {
           dynamicFee: 0,
           poolFeeBps: 0,
         },
+        minMigrationQuoteThreshold: new BN(0),
+        migrationDeadlineDuration: new BN(0),
+        circuitBreakerMaxPriceChangeBps: 0,
+        circuitBreakerWindow: 0,
+        padding0: [],
+        partnerAndCreatorSurplusShare: 0,
+        creatorSurplusPercentage: 0,
+        poolCreationFeeToken: 0,
+        poolCreationFee: new BN(0),
+        baseTransferFeeBps: 0,
+        baseTransferFeeAuthority: 0,
+        nonTransferableUntilMigration: 0,
+        padding1: [],
+        baseMaxTransferFee: new BN(0),
         padding: [],
         curve: curves,
       };
@@ -465,6 +479,20 @@ describe("Fee Swap test", () => {
           dynamicFee: 0,
           poolFeeBps: 0,
         },
+        minMigrationQuoteThreshold: new BN(0),
+        migrationDeadlineDuration: new BN(0),
+        circuitBreakerMaxPriceChangeBps: 0,
+        circuitBreakerWindow: 0,
+        padding0: [],
+        partnerAndCreatorSurplusShare: 0,
+        creatorSurplusPercentage: 0,
+        poolCreationFeeToken: 0,
+        poolCreationFee: new BN(0),
+        baseTransferFeeBps: 0,
+        baseTransferFeeAuthority: 0,
+        nonTransferableUntilMigration: 0,
+        padding1: [],
+        baseMaxTransferFee: new BN(0),
         padding: [],
         curve: curves,
       };
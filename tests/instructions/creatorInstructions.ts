@@ -92,7 +92,9 @@ export async function claimCreatorTradingFee(
       quoteVault: poolState.quoteVault,
       baseMint: poolState.baseMint,
       quoteMint: quoteMintInfo.mint,
-      creator: creator.publicKey,
+      signer: creator.publicKey,
+      delegatedOperator: null,
+      creatorFeeSplit: null,
       tokenBaseProgram,
       tokenQuoteProgram,
     })
@@ -150,7 +152,9 @@ export async function creatorWithdrawSurplus(
       tokenQuoteAccount,
       quoteVault: poolState.quoteVault,
       quoteMint: quoteMintInfo.mint,
-      creator: creator.publicKey,
+      signer: creator.publicKey,
+      delegatedOperator: null,
+      creatorFeeSplit: null,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
     })
     .preInstructions(preInstructions)
@@ -167,29 +171,44 @@ export async function transferCreator(
   program: VirtualCurveProgram,
   virtualPool: PublicKey,
   creator: Keypair,
-  newCreator: PublicKey
+  newCreator: Keypair
 ): Promise<void> {
   const poolState = await getVirtualPool(banksClient, program, virtualPool);
   const migrationMetadata = deriveMigrationMetadataAddress(virtualPool);
-  const transaction = await program.methods
-    .transferPoolCreator()
+  const proposeTransaction = await program.methods
+    .proposePoolCreator()
     .accountsPartial({
       virtualPool,
-      newCreator,
-      config: poolState.config,
+      newCreator: newCreator.publicKey,
       creator: creator.publicKey,
-    }).remainingAccounts(
-      [
-        {
-          isSigner: false,
-          isWritable: false,
-          pubkey: migrationMetadata,
-        }]
-    )
+    })
     .transaction();
-  transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
-  transaction.sign(creator);
-  await processTransactionMaybeThrow(banksClient, transaction);
+  proposeTransaction.recentBlockhash = (
+    await banksClient.getLatestBlockhash()
+  )[0];
+  proposeTransaction.sign(creator);
+  await processTransactionMaybeThrow(banksClient, proposeTransaction);
+
+  const acceptTransaction = await program.methods
+    .acceptPoolCreator()
+    .accountsPartial({
+      virtualPool,
+      config: poolState.config,
+      pendingCreator: newCreator.publicKey,
+    })
+    .remainingAccounts([
+      {
+        isSigner: false,
+        isWritable: false,
+        pubkey: migrationMetadata,
+      },
+    ])
+    .transaction();
+  acceptTransaction.recentBlockhash = (
+    await banksClient.getLatestBlockhash()
+  )[0];
+  acceptTransaction.sign(newCreator);
+  await processTransactionMaybeThrow(banksClient, acceptTransaction);
 }
 
 export type CreatorWithdrawMigrationFeeParams = {
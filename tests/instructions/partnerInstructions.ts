@@ -94,6 +94,20 @@ export type ConfigParameters = {
     collectFeeMode: number;
     dynamicFee: number;
   },
+  minMigrationQuoteThreshold: BN;
+  migrationDeadlineDuration: BN;
+  circuitBreakerMaxPriceChangeBps: number;
+  circuitBreakerWindow: number;
+  padding0: number[];
+  partnerAndCreatorSurplusShare: number;
+  creatorSurplusPercentage: number;
+  poolCreationFeeToken: number;
+  poolCreationFee: BN;
+  baseTransferFeeBps: number;
+  baseTransferFeeAuthority: number;
+  nonTransferableUntilMigration: number;
+  padding1: number[];
+  baseMaxTransferFee: BN;
   padding: BN[];
   curve: Array<LiquidityDistributionParameters>;
 };
@@ -263,7 +277,9 @@ export async function claimTradingFee(
       quoteVault: poolState.quoteVault,
       baseMint: poolState.baseMint,
       quoteMint: quoteMintInfo.mint,
-      feeClaimer: feeClaimer.publicKey,
+      signer: feeClaimer.publicKey,
+      delegatedOperator: null,
+      feeSplit: null,
       tokenBaseProgram,
       tokenQuoteProgram,
     })
@@ -321,7 +337,9 @@ export async function partnerWithdrawSurplus(
       tokenQuoteAccount,
       quoteVault: poolState.quoteVault,
       quoteMint: quoteMintInfo.mint,
-      feeClaimer: feeClaimer.publicKey,
+      signer: feeClaimer.publicKey,
+      delegatedOperator: null,
+      feeSplit: null,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
     })
     .preInstructions(preInstructions)
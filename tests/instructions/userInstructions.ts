@@ -9,6 +9,7 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import {
+  deriveGlobalStateAddress,
   deriveMetadataAccount,
   derivePoolAddress,
   derivePoolAuthority,
@@ -100,6 +101,7 @@ export async function createPoolWithSplToken(
       metadataProgram: METAPLEX_PROGRAM_ID,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
       tokenProgram,
+      tokenMetadataConfig: null,
     })
     .transaction();
     transaction.add(
@@ -141,6 +143,7 @@ export async function createPoolWithToken2022(
       quoteVault,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
+      tokenMetadataConfig: null,
     })
     .transaction();
   
@@ -267,6 +270,7 @@ export async function swap(
       tokenBaseProgram,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
       referralTokenAccount,
+      globalState: deriveGlobalStateAddress(),
     }).remainingAccounts( // TODO should check condition to add this in remaning accounts
       [
         {
@@ -375,6 +379,7 @@ export async function getSwapInstruction(
       tokenBaseProgram,
       tokenQuoteProgram: TOKEN_PROGRAM_ID,
       referralTokenAccount,
+      globalState: deriveGlobalStateAddress(),
     }).remainingAccounts(
       [
         {
@@ -544,3 +549,36 @@ export async function createVirtualPoolMetadata(
   expect(metadataState.website.toString()).equal(website.toString());
   expect(metadataState.logo.toString()).equal(logo.toString());
 }
+
+export type ThawBaseTokenAccountParams = {
+  payer: Keypair;
+  virtualPool: PublicKey;
+  tokenAccount: PublicKey;
+};
+export async function thawBaseTokenAccount(
+  banksClient: BanksClient,
+  program: VirtualCurveProgram,
+  params: ThawBaseTokenAccountParams
+) {
+  const { payer, virtualPool, tokenAccount } = params;
+  const poolState = await getVirtualPool(banksClient, program, virtualPool);
+  const configState = await getConfig(banksClient, program, poolState.config);
+  const tokenBaseProgram =
+    configState.tokenType == 0 ? TOKEN_PROGRAM_ID : TOKEN_2022_PROGRAM_ID;
+
+  const transaction = await program.methods
+    .thawBaseTokenAccount()
+    .accountsPartial({
+      poolAuthority: derivePoolAuthority(),
+      pool: virtualPool,
+      baseMint: poolState.baseMint,
+      tokenAccount,
+      tokenBaseProgram,
+    })
+    .transaction();
+
+  transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+  transaction.sign(payer);
+
+  await processTransactionMaybeThrow(banksClient, transaction);
+}
@@ -0,0 +1,344 @@
+import { BN } from "bn.js";
+import { BanksClient, ProgramTestContext } from "solana-bankrun";
+import {
+    BaseFee,
+    ConfigParameters,
+    createConfig,
+    CreateConfigParams,
+    createPoolWithSplToken,
+    swap,
+    SwapParams,
+    thawBaseTokenAccount,
+} from "./instructions";
+import { Pool, VirtualCurveProgram } from "./utils/types";
+import {
+    Keypair,
+    LAMPORTS_PER_SOL,
+    PublicKey,
+    Transaction,
+} from "@solana/web3.js";
+import {
+    createDammV2Config,
+    fundSol,
+    getMint,
+    getTokenAccount,
+    processTransactionMaybeThrow,
+    startTest,
+} from "./utils";
+import {
+    createVirtualCurveProgram,
+    derivePoolAuthority,
+    MAX_SQRT_PRICE,
+    MIN_SQRT_PRICE,
+    U64_MAX,
+} from "./utils";
+import { getVirtualPool } from "./utils/fetcher";
+import {
+    AccountState,
+    createAssociatedTokenAccountIdempotentInstruction,
+    createTransferInstruction,
+    getAssociatedTokenAddressSync,
+    NATIVE_MINT,
+} from "@solana/spl-token";
+
+import { createMeteoraDammV2Metadata, migrateToDammV2 } from "./instructions/dammV2Migration";
+import { assert, expect } from "chai";
+
+async function transferBaseToken(
+    banksClient: BanksClient,
+    owner: Keypair,
+    baseMint: PublicKey,
+    receiver: PublicKey,
+    amount: BN
+) {
+    const source = getAssociatedTokenAddressSync(baseMint, owner.publicKey);
+    const destination = getAssociatedTokenAddressSync(baseMint, receiver);
+    const transaction = new Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+            owner.publicKey,
+            destination,
+            receiver,
+            baseMint
+        ),
+        createTransferInstruction(
+            source,
+            destination,
+            owner.publicKey,
+            BigInt(amount.toString())
+        )
+    );
+    transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+    transaction.sign(owner);
+    await processTransactionMaybeThrow(banksClient, transaction);
+}
+
+describe("Non-transferable until migration", () => {
+    let context: ProgramTestContext;
+    let admin: Keypair;
+    let partner: Keypair;
+    let user: Keypair;
+    let receiver: Keypair;
+    let poolCreator: Keypair;
+    let program: VirtualCurveProgram;
+    let config: PublicKey;
+    let virtualPool: PublicKey;
+    let virtualPoolState: Pool;
+    let userBaseTokenAccount: PublicKey;
+
+    before(async () => {
+        context = await startTest();
+        admin = context.payer;
+        partner = Keypair.generate();
+        user = Keypair.generate();
+        receiver = Keypair.generate();
+        poolCreator = Keypair.generate();
+        const receivers = [
+            partner.publicKey,
+            user.publicKey,
+            receiver.publicKey,
+            poolCreator.publicKey,
+        ];
+        await fundSol(context.banksClient, admin, receivers);
+        program = createVirtualCurveProgram();
+    });
+
+    it("Partner create non-transferable config", async () => {
+        const baseFee: BaseFee = {
+            cliffFeeNumerator: new BN(2_500_000),
+            firstFactor: 0,
+            secondFactor: new BN(0),
+            thirdFactor: new BN(0),
+            baseFeeMode: 0,
+        };
+
+        const curves = [];
+
+        for (let i = 1; i <= 16; i++) {
+            if (i == 16) {
+                curves.push({
+                    sqrtPrice: MAX_SQRT_PRICE,
+                    liquidity: U64_MAX.shln(30 + i),
+                });
+            } else {
+                curves.push({
+                    sqrtPrice: MAX_SQRT_PRICE.muln(i * 5).divn(100),
+                    liquidity: U64_MAX.shln(30 + i),
+                });
+            }
+        }
+
+        const instructionParams: ConfigParameters = {
+            poolFees: {
+                baseFee,
+                dynamicFee: null,
+            },
+            activationType: 0,
+            collectFeeMode: 0,
+            migrationOption: 1,
+            tokenType: 0, // spl_token
+            tokenDecimal: 6,
+            migrationQuoteThreshold: new BN(LAMPORTS_PER_SOL * 5),
+            partnerLpPercentage: 20,
+            creatorLpPercentage: 20,
+            partnerLockedLpPercentage: 55,
+            creatorLockedLpPercentage: 5,
+            sqrtStartPrice: MIN_SQRT_PRICE.shln(32),
+            lockedVesting: {
+                amountPerPeriod: new BN(0),
+                cliffDurationFromMigrationTime: new BN(0),
+                frequency: new BN(0),
+                numberOfPeriod: new BN(0),
+                cliffUnlockAmount: new BN(0),
+            },
+            migrationFeeOption: 0,
+            tokenSupply: null,
+            creatorTradingFeePercentage: 0,
+            tokenUpdateAuthority: 0,
+            migrationFee: {
+                feePercentage: 0,
+                creatorFeePercentage: 0,
+            },
+            migratedPoolFee: {
+                collectFeeMode: 0,
+                dynamicFee: 0,
+                poolFeeBps: 0,
+            },
+            minMigrationQuoteThreshold: new BN(0),
+            migrationDeadlineDuration: new BN(0),
+            circuitBreakerMaxPriceChangeBps: 0,
+            circuitBreakerWindow: 0,
+            padding0: [],
+            partnerAndCreatorSurplusShare: 0,
+            creatorSurplusPercentage: 0,
+            poolCreationFeeToken: 0,
+            poolCreationFee: new BN(0),
+            baseTransferFeeBps: 0,
+            baseTransferFeeAuthority: 0,
+            nonTransferableUntilMigration: 1,
+            padding1: [],
+            baseMaxTransferFee: new BN(0),
+            padding: [],
+            curve: curves,
+        };
+        const params: CreateConfigParams = {
+            payer: partner,
+            leftoverReceiver: partner.publicKey,
+            feeClaimer: partner.publicKey,
+            quoteMint: NATIVE_MINT,
+            instructionParams,
+        };
+        config = await createConfig(context.banksClient, program, params);
+    });
+
+    it("Create spl pool from config", async () => {
+        virtualPool = await createPoolWithSplToken(context.banksClient, program, {
+            poolCreator,
+            payer: poolCreator,
+            quoteMint: NATIVE_MINT,
+            config,
+            instructionParams: {
+                name: "test token spl",
+                symbol: "TEST",
+                uri: "abc.com",
+            },
+        });
+        virtualPoolState = await getVirtualPool(
+            context.banksClient,
+            program,
+            virtualPool
+        );
+        userBaseTokenAccount = getAssociatedTokenAddressSync(
+            virtualPoolState.baseMint,
+            user.publicKey
+        );
+    });
+
+    it("Base token account of buyer is frozen after swap", async () => {
+        const params: SwapParams = {
+            config,
+            payer: user,
+            pool: virtualPool,
+            inputTokenMint: NATIVE_MINT,
+            outputTokenMint: virtualPoolState.baseMint,
+            amountIn: new BN(LAMPORTS_PER_SOL * 5.5),
+            minimumAmountOut: new BN(0),
+            referralTokenAccount: null,
+        };
+        await swap(context.banksClient, program, params);
+
+        const userBaseToken = await getTokenAccount(
+            context.banksClient,
+            userBaseTokenAccount
+        );
+        expect(userBaseToken.state).equal(AccountState.Frozen);
+        expect(Number(userBaseToken.amount)).greaterThan(0);
+
+        virtualPoolState = await getVirtualPool(
+            context.banksClient,
+            program,
+            virtualPool
+        );
+        expect(virtualPoolState.frozenAccountCount.toNumber()).equal(1);
+    });
+
+    it("Frozen holder can not transfer base token before migration", async () => {
+        try {
+            await transferBaseToken(
+                context.banksClient,
+                user,
+                virtualPoolState.baseMint,
+                receiver.publicKey,
+                new BN(1)
+            );
+            assert.ok(false);
+        } catch (e) {
+            //
+        }
+    });
+
+    it("Holder can not be thawed before migration", async () => {
+        try {
+            await thawBaseTokenAccount(context.banksClient, program, {
+                payer: receiver,
+                virtualPool,
+                tokenAccount: userBaseTokenAccount,
+            });
+            assert.ok(false);
+        } catch (e) {
+            //
+        }
+    });
+
+    it("Migrate to Meteora Damm V2 Pool", async () => {
+        await createMeteoraDammV2Metadata(context.banksClient, program, {
+            payer: admin,
+            virtualPool,
+            config,
+        });
+        const dammConfig = await createDammV2Config(
+            context.banksClient,
+            admin,
+            derivePoolAuthority()
+        );
+        await migrateToDammV2(context.banksClient, program, {
+            payer: admin,
+            virtualPool,
+            dammConfig,
+        });
+
+        // freeze authority is kept until every frozen holder is thawed
+        const baseMint = await getMint(
+            context.banksClient,
+            virtualPoolState.baseMint
+        );
+        expect(baseMint.freezeAuthorityOption).equal(1);
+        expect(baseMint.freezeAuthority.toString()).equal(
+            derivePoolAuthority().toString()
+        );
+    });
+
+    it("Holder can transfer base token after thaw", async () => {
+        // anyone can thaw a holder after migration
+        await thawBaseTokenAccount(context.banksClient, program, {
+            payer: receiver,
+            virtualPool,
+            tokenAccount: userBaseTokenAccount,
+        });
+
+        const userBaseToken = await getTokenAccount(
+            context.banksClient,
+            userBaseTokenAccount
+        );
+        expect(userBaseToken.state).equal(AccountState.Initialized);
+
+        virtualPoolState = await getVirtualPool(
+            context.banksClient,
+            program,
+            virtualPool
+        );
+        expect(virtualPoolState.frozenAccountCount.toNumber()).equal(0);
+
+        // last frozen holder is thawed, freeze authority is revoked
+        const baseMint = await getMint(
+            context.banksClient,
+            virtualPoolState.baseMint
+        );
+        expect(baseMint.freezeAuthorityOption).equal(0);
+
+        await transferBaseToken(
+            context.banksClient,
+            user,
+            virtualPoolState.baseMint,
+            receiver.publicKey,
+            new BN(1)
+        );
+        const receiverBaseToken = await getTokenAccount(
+            context.banksClient,
+            getAssociatedTokenAddressSync(
+                virtualPoolState.baseMint,
+                receiver.publicKey
+            )
+        );
+        expect(Number(receiverBaseToken.amount)).equal(1);
+    });
+});
@@ -226,7 +226,7 @@ async function fullFlowUpdateCreatorInPreBondingCurve(
     program,
     virtualPool,
     poolCreator,
-    newCreator.publicKey
+    newCreator
   );
 
   let configState = await getConfig(banksClient, program, config);
@@ -358,7 +358,7 @@ async function fullFlowUpdateCreatorPoolCreated(
     program,
     virtualPool,
     poolCreator,
-    newCreator.publicKey
+    newCreator
   );
 
   //  new creator claim trading fee
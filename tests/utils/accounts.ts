@@ -44,6 +44,20 @@ export function derivePoolAuthority(): PublicKey {
   )[0];
 }
 
+export function deriveGlobalStateAddress(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("global_state")],
+    DYNAMIC_BONDING_CURVE_PROGRAM_ID
+  )[0];
+}
+
+export function deriveProtocolConfigAddress(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config")],
+    DYNAMIC_BONDING_CURVE_PROGRAM_ID
+  )[0];
+}
+
 export function deriveBaseKeyForLocker(virtualPool: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("base_locker"), virtualPool.toBuffer()],
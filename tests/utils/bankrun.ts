@@ -4,6 +4,7 @@ import {
   PublicKey,
   SystemProgram,
 } from "@solana/web3.js";
+import { createHash } from "crypto";
 import { startAnchor } from "solana-bankrun";
 import {
   DAMM_PROGRAM_ID,
@@ -12,7 +13,11 @@ import {
   LOCKER_PROGRAM_ID,
   VAULT_PROGRAM_ID,
 } from "./constants";
-import { METAPLEX_PROGRAM_ID } from ".";
+import {
+  deriveGlobalStateAddress,
+  deriveProtocolConfigAddress,
+  METAPLEX_PROGRAM_ID,
+} from ".";
 import {
   ACCOUNT_SIZE,
   AccountLayout,
@@ -58,6 +63,22 @@ AccountLayout.encode(
   tokenAccData
 );
 
+function accountDiscriminator(name: string): Buffer {
+  return createHash("sha256")
+    .update(`account:${name}`)
+    .digest()
+    .subarray(0, 8);
+}
+
+// protocol config with local admin as the only admin, size is 8 + ProtocolConfig::INIT_SPACE
+const protocolConfigData = Buffer.alloc(8 + 448);
+accountDiscriminator("ProtocolConfig").copy(protocolConfigData, 0);
+LOCAL_ADMIN_KEYPAIR.publicKey.toBuffer().copy(protocolConfigData, 8);
+
+// global state without emergency stop, size is 8 + GlobalState::INIT_SPACE
+const globalStateData = Buffer.alloc(8 + 136);
+accountDiscriminator("GlobalState").copy(globalStateData, 0);
+
 export async function startTest() {
   // Program name need to match fixtures program name
   return startAnchor(
@@ -107,6 +128,25 @@ export async function startTest() {
           executable: false,
         },
       },
+      // protocol config and global state are created by admin instructions on chain
+      {
+        address: deriveProtocolConfigAddress(),
+        info: {
+          lamports: LAMPORTS_PER_SOL,
+          data: protocolConfigData,
+          owner: DYNAMIC_BONDING_CURVE_PROGRAM_ID,
+          executable: false,
+        },
+      },
+      {
+        address: deriveGlobalStateAddress(),
+        info: {
+          lamports: LAMPORTS_PER_SOL,
+          data: globalStateData,
+          owner: DYNAMIC_BONDING_CURVE_PROGRAM_ID,
+          executable: false,
+        },
+      },
     ]
   );
 }